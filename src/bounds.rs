@@ -0,0 +1,100 @@
+use crate::graph::Graph;
+
+// Limites para o número de dominação romana γR(G), usados para medir a distância entre o
+// fitness do AG e o ótimo. Todos os limites inferiores são calculados por componente conexa,
+// já que γR é a soma dos valores das componentes
+#[derive(Clone, Debug)]
+pub struct Bounds {
+    // Σ ⌈2n/(Δ+1)⌉: cada vértice rotulado com 2 domina no máximo Δ+1 vértices
+    pub degree: usize,
+    // Tamanho de um empacotamento 2-limitado (cada vizinhança fechada contém no máximo dois
+    // vértices escolhidos): é uma solução do dual da relaxação linear do problema
+    pub packing: usize,
+    // γR ≥ γ + 1 em componentes com mais de um vértice, com γ limitado inferiormente por
+    // ⌈n/(Δ+1)⌉, pelo 2-empacotamento e por n - m
+    pub domination: usize,
+    // Soma, por componente, do melhor dos limites acima
+    pub lower: usize,
+    // Melhor peso entre as heurísticas h2, h3 e h4
+    pub upper: usize,
+}
+
+impl Bounds {
+    pub fn new(graph: &Graph) -> Self {
+        let mut count = vec![0; graph.get_num_vertices()];
+        let mut bounds = Bounds {
+            degree: 0,
+            packing: 0,
+            domination: 0,
+            lower: 0,
+            upper: upper_bound(graph),
+        };
+
+        for component in graph.get_connected_components() {
+            let n = component.len();
+            let (degree, packing, domination) = if n == 1 {
+                (1, 1, 1)
+            } else {
+                let m = component
+                    .iter()
+                    .map(|&v| graph.get_vertex_degree(v))
+                    .sum::<usize>()
+                    / 2;
+                let max_degree = component
+                    .iter()
+                    .map(|&v| graph.get_vertex_degree(v))
+                    .max()
+                    .unwrap();
+                let gamma = (n.div_ceil(max_degree + 1))
+                    .max(greedy_packing(graph, &component, 1, &mut count))
+                    .max(n.saturating_sub(m));
+                (
+                    (2 * n).div_ceil(max_degree + 1),
+                    greedy_packing(graph, &component, 2, &mut count),
+                    gamma + 1,
+                )
+            };
+
+            bounds.degree += degree;
+            bounds.packing += packing;
+            bounds.domination += domination;
+            bounds.lower += degree.max(packing).max(domination);
+        }
+        bounds
+    }
+
+    // Distância entre o fitness e o melhor limite inferior; 0 indica solução ótima
+    pub fn gap(&self, fitness: usize) -> usize {
+        fitness.saturating_sub(self.lower)
+    }
+}
+
+// Escolhe gulosamente, dos vértices de menor grau para os de maior, um conjunto em que cada
+// vizinhança fechada contém no máximo limit vértices escolhidos. O vetor count é compartilhado
+// entre chamadas e restaurado ao final
+fn greedy_packing(graph: &Graph, vertices: &[usize], limit: usize, count: &mut [usize]) -> usize {
+    let mut order = vertices.to_vec();
+    order.sort_by_key(|&v| graph.get_vertex_degree(v));
+
+    let mut chosen = 0;
+    for v in order {
+        let closed = || std::iter::once(v).chain(graph.get_neighbors(v).iter().copied());
+        if closed().all(|u| count[u] < limit) {
+            closed().for_each(|u| count[u] += 1);
+            chosen += 1;
+        }
+    }
+
+    for &v in vertices {
+        count[v] = 0;
+    }
+    chosen
+}
+
+fn upper_bound(graph: &Graph) -> usize {
+    [graph.h2(), graph.h3(), graph.h4()]
+        .iter()
+        .map(|labels| labels.iter().map(|&l| l as usize).sum())
+        .min()
+        .unwrap_or(0)
+}
@@ -0,0 +1,235 @@
+use std::time::{Duration, Instant};
+
+use crate::bounds::Bounds;
+use crate::genetic_algorithm::Solution;
+use crate::graph::Graph;
+use crate::verify::verify;
+
+// Resultado do solver exato. Quando um limite de nós ou de tempo interrompe a busca, a
+// solução é a melhor encontrada e lower_bound é o melhor limite inferior provado
+pub struct ExactResult {
+    pub solution: Solution,
+    pub lower_bound: usize,
+    pub optimal: bool,
+    pub nodes: usize,
+}
+
+impl ExactResult {
+    pub fn gap(&self) -> usize {
+        self.solution
+            .fitness
+            .unwrap_or_default()
+            .saturating_sub(self.lower_bound)
+    }
+}
+
+struct Search<'a> {
+    graph: &'a Graph,
+    labels: Vec<u8>,
+    // Vértices que não podem mais receber 2 no ramo atual
+    forbidden: Vec<bool>,
+    // dominated[v] conta o próprio rótulo positivo de v e os vizinhos com rótulo 2
+    dominated: Vec<usize>,
+    cost: usize,
+    best: Vec<u8>,
+    best_cost: usize,
+    nodes: usize,
+    node_limit: Option<usize>,
+    deadline: Option<Instant>,
+    aborted: bool,
+}
+
+impl Search<'_> {
+    fn can_be_2(&self, w: usize) -> bool {
+        self.labels[w] == 0 && !self.forbidden[w]
+    }
+
+    fn closed_neighborhood(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::once(v).chain(self.graph.get_neighbors(v).iter().copied())
+    }
+
+    // Quantos vértices ainda não dominados passariam a ser dominados se w recebesse 2
+    fn gain(&self, w: usize) -> usize {
+        self.closed_neighborhood(w)
+            .filter(|&u| self.dominated[u] == 0)
+            .count()
+    }
+
+    fn set_label(&mut self, v: usize, label: u8) {
+        self.labels[v] = label;
+        self.cost += label as usize;
+        self.dominated[v] += 1;
+        if label == 2 {
+            for &u in self.graph.get_neighbors(v) {
+                self.dominated[u] += 1;
+            }
+        }
+    }
+
+    fn unset_label(&mut self, v: usize) {
+        let label = self.labels[v];
+        self.labels[v] = 0;
+        self.cost -= label as usize;
+        self.dominated[v] -= 1;
+        if label == 2 {
+            for &u in self.graph.get_neighbors(v) {
+                self.dominated[u] -= 1;
+            }
+        }
+    }
+
+    // Cada vértice não dominado precisa de rótulo 1 (custo 1) ou de um vizinho fechado w com
+    // rótulo 2, cujo custo 2 se divide entre os gain(w) vértices que ele domina
+    fn lower_bound(&self) -> usize {
+        let n = self.graph.get_num_vertices();
+        let gains: Vec<usize> = (0..n)
+            .map(|w| if self.can_be_2(w) { self.gain(w) } else { 0 })
+            .collect();
+
+        let mut bound = 0.0;
+        for u in (0..n).filter(|&u| self.dominated[u] == 0) {
+            let share = self
+                .closed_neighborhood(u)
+                .filter(|&w| gains[w] > 0)
+                .map(|w| 2.0 / gains[w] as f64)
+                .fold(1.0, f64::min);
+            bound += share;
+        }
+        self.cost + (bound - 1e-9).ceil() as usize
+    }
+
+    fn limit_reached(&self) -> bool {
+        self.node_limit.is_some_and(|limit| self.nodes >= limit)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    fn branch(&mut self) {
+        if self.limit_reached() {
+            self.aborted = true;
+            return;
+        }
+        self.nodes += 1;
+
+        // Ramifica no vértice não dominado com menos candidatos a receber 2
+        let n = self.graph.get_num_vertices();
+        let vertex = (0..n).filter(|&v| self.dominated[v] == 0).min_by_key(|&v| {
+            self.closed_neighborhood(v)
+                .filter(|&w| self.can_be_2(w))
+                .count()
+        });
+        let Some(vertex) = vertex else {
+            if self.cost < self.best_cost {
+                self.best_cost = self.cost;
+                self.best = self.labels.clone();
+            }
+            return;
+        };
+        if self.lower_bound() >= self.best_cost {
+            return;
+        }
+
+        let mut candidates: Vec<usize> = self
+            .closed_neighborhood(vertex)
+            .filter(|&w| self.can_be_2(w))
+            .collect();
+        candidates.sort_by_key(|&w| std::cmp::Reverse(self.gain(w)));
+
+        // Cada candidato recebe 2 em seu ramo e fica proibido nos ramos seguintes, para que
+        // nenhuma rotulação seja enumerada duas vezes
+        for &w in &candidates {
+            self.set_label(w, 2);
+            self.branch();
+            self.unset_label(w);
+            self.forbidden[w] = true;
+            if self.aborted {
+                break;
+            }
+        }
+        if !self.aborted {
+            self.set_label(vertex, 1);
+            self.branch();
+            self.unset_label(vertex);
+        }
+        for &w in &candidates {
+            self.forbidden[w] = false;
+        }
+    }
+}
+
+fn weight(labels: &[u8]) -> usize {
+    labels.iter().map(|&l| l as usize).sum()
+}
+
+// Resolve γR(G) por branch-and-bound, componente a componente. A solução inicial (por exemplo,
+// a melhor do AG), quando viável, e as heurísticas h2, h3 e h4 servem de incumbente; os
+// limites de nós e de tempo valem para a busca inteira
+pub fn solve(
+    graph: &Graph,
+    incumbent: Option<&[u8]>,
+    node_limit: Option<usize>,
+    time_limit: Option<Duration>,
+) -> ExactResult {
+    let deadline = time_limit.map(|limit| Instant::now() + limit);
+    let incumbent = incumbent.filter(|labels| labels.len() == graph.get_num_vertices());
+    let mut labels = vec![0; graph.get_num_vertices()];
+    let mut lower_bound = 0;
+    let mut optimal = true;
+    let mut nodes = 0;
+
+    for component in graph.get_connected_components() {
+        let subgraph = graph.induced_subgraph(&component);
+        let n = subgraph.get_num_vertices();
+
+        // A restrição de uma rotulação viável a uma componente continua viável
+        let mut best = [subgraph.h2(), subgraph.h3(), subgraph.h4()]
+            .into_iter()
+            .min_by_key(|labels| weight(labels))
+            .unwrap();
+        // A solução inicial só é aproveitada se for viável na componente; uma rotulação
+        // inválida daria um incumbente mais leve que o ótimo e a busca o declararia ótimo
+        if let Some(incumbent) = incumbent {
+            let restricted: Vec<u8> = component.iter().map(|&v| incumbent[v]).collect();
+            let valid = verify(&subgraph.freeze(), &restricted)
+                .is_ok_and(|verification| verification.is_valid());
+            if valid && weight(&restricted) < weight(&best) {
+                best = restricted;
+            }
+        }
+
+        let mut search = Search {
+            graph: &subgraph,
+            labels: vec![0; n],
+            forbidden: vec![false; n],
+            dominated: vec![0; n],
+            cost: 0,
+            best_cost: weight(&best),
+            best,
+            nodes: 0,
+            node_limit: node_limit.map(|limit| limit.saturating_sub(nodes)),
+            deadline,
+            aborted: false,
+        };
+        let root_bound = search.lower_bound().max(Bounds::new(&subgraph).lower);
+        search.branch();
+
+        nodes += search.nodes;
+        if search.aborted {
+            optimal = false;
+            lower_bound += root_bound.min(search.best_cost);
+        } else {
+            lower_bound += search.best_cost;
+        }
+        for (i, &v) in component.iter().enumerate() {
+            labels[v] = search.best[i];
+        }
+    }
+
+    ExactResult {
+        solution: Solution::from_labels(labels),
+        lower_bound,
+        optimal,
+        nodes,
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::{Rng, SeedableRng};
+
+use crate::graph::Graph;
+
+// Geradores de instâncias sintéticas. Os geradores aleatórios recebem uma semente, de modo
+// que a mesma chamada sempre produz o mesmo grafo
+
+// Erdős–Rényi G(n, p): cada par de vértices é ligado com probabilidade p
+pub fn erdos_renyi_gnp(n: usize, p: f64, seed: u64) -> Graph {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut edges = vec![];
+    for v in 1..n {
+        for u in 0..v {
+            if rng.gen::<f64>() < p {
+                edges.push((u, v));
+            }
+        }
+    }
+    Graph::new(n, &edges)
+}
+
+// Erdős–Rényi G(n, m): m arestas distintas escolhidas uniformemente
+pub fn erdos_renyi_gnm(n: usize, m: usize, seed: u64) -> Graph {
+    let max_edges = n * n.saturating_sub(1) / 2;
+    assert!(
+        m <= max_edges,
+        "G(n, m) com n = {} admite no máximo {} arestas",
+        n,
+        max_edges
+    );
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let edges: Vec<(usize, usize)> = index::sample(&mut rng, max_edges, m)
+        .into_iter()
+        .map(|k| {
+            // Converte o índice k no par (u, v) do triângulo superior, coluna a coluna
+            let mut v = (((8 * k + 1) as f64).sqrt() as usize).div_ceil(2);
+            while v * (v - 1) / 2 > k {
+                v -= 1;
+            }
+            while (v + 1) * v / 2 <= k {
+                v += 1;
+            }
+            (k - v * (v - 1) / 2, v)
+        })
+        .collect();
+    Graph::new(n, &edges)
+}
+
+// Barabási–Albert: cada novo vértice se liga a m vértices escolhidos com probabilidade
+// proporcional ao grau
+pub fn barabasi_albert(n: usize, m: usize, seed: u64) -> Graph {
+    assert!(m >= 1 && m < n, "Barabási–Albert exige 1 <= m < n");
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut edges = vec![];
+    let mut targets: Vec<usize> = (0..m).collect();
+    let mut repeated_nodes: Vec<usize> = vec![];
+
+    for source in m..n {
+        for &target in &targets {
+            edges.push((source, target));
+        }
+        repeated_nodes.extend(&targets);
+        repeated_nodes.extend(std::iter::repeat_n(source, m));
+
+        let mut chosen: HashSet<usize> = HashSet::new();
+        while chosen.len() < m {
+            chosen.insert(*repeated_nodes.choose(&mut rng).unwrap());
+        }
+        targets = chosen.into_iter().collect();
+        targets.sort_unstable();
+    }
+    Graph::new(n, &edges)
+}
+
+// Watts–Strogatz: anel em que cada vértice se liga aos k vizinhos mais próximos, com cada
+// aresta religada com probabilidade beta
+pub fn watts_strogatz(n: usize, k: usize, beta: f64, seed: u64) -> Graph {
+    assert!(k < n, "Watts–Strogatz exige k < n");
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let key = |u: usize, v: usize| (u.min(v), u.max(v));
+    let mut edges: HashSet<(usize, usize)> = HashSet::new();
+    for u in 0..n {
+        for j in 1..=k / 2 {
+            edges.insert(key(u, (u + j) % n));
+        }
+    }
+
+    let mut degree = vec![0; n];
+    for &(u, v) in &edges {
+        degree[u] += 1;
+        degree[v] += 1;
+    }
+
+    for j in 1..=k / 2 {
+        for u in 0..n {
+            let v = (u + j) % n;
+            // Evita laços e arestas repetidas; vértices já ligados a todos ficam como estão
+            if rng.gen::<f64>() >= beta || !edges.contains(&key(u, v)) || degree[u] >= n - 1 {
+                continue;
+            }
+            let w = loop {
+                let w = rng.gen_range(0..n);
+                if w != u && !edges.contains(&key(u, w)) {
+                    break w;
+                }
+            };
+            edges.remove(&key(u, v));
+            edges.insert(key(u, w));
+            degree[v] -= 1;
+            degree[w] += 1;
+        }
+    }
+
+    let mut edges: Vec<(usize, usize)> = edges.into_iter().collect();
+    edges.sort_unstable();
+    Graph::new(n, &edges)
+}
+
+// Grafo geométrico aleatório: n pontos no quadrado unitário, ligados quando a distância
+// entre eles é no máximo radius
+pub fn random_geometric(n: usize, radius: f64, seed: u64) -> Graph {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let points: Vec<(f64, f64)> = (0..n).map(|_| (rng.gen(), rng.gen())).collect();
+
+    let mut edges = vec![];
+    for v in 1..n {
+        for u in 0..v {
+            let (dx, dy) = (points[u].0 - points[v].0, points[u].1 - points[v].1);
+            if dx * dx + dy * dy <= radius * radius {
+                edges.push((u, v));
+            }
+        }
+    }
+    Graph::new(n, &edges)
+}
+
+// Grade rows x cols com vizinhança de 4; o vértice (r, c) é r * cols + c
+pub fn grid(rows: usize, cols: usize) -> Graph {
+    lattice(rows, cols, false, false)
+}
+
+// Grade rows x cols com vizinhança de 8 (inclui diagonais), como a instância gr_30_30
+pub fn grid_with_diagonals(rows: usize, cols: usize) -> Graph {
+    lattice(rows, cols, true, false)
+}
+
+// Toro rows x cols: grade com vizinhança de 4 cujas bordas se ligam
+pub fn torus(rows: usize, cols: usize) -> Graph {
+    lattice(rows, cols, false, true)
+}
+
+fn lattice(rows: usize, cols: usize, diagonals: bool, wrap: bool) -> Graph {
+    let mut offsets = vec![(0, 1), (1, 0)];
+    if diagonals {
+        offsets.extend([(1, 1), (1, -1)]);
+    }
+
+    let mut edges = vec![];
+    for r in 0..rows {
+        for c in 0..cols {
+            for &(dr, dc) in &offsets {
+                let (nr, nc) = (r as isize + dr, c as isize + dc);
+                let (nr, nc) = if wrap {
+                    (
+                        nr.rem_euclid(rows as isize) as usize,
+                        nc.rem_euclid(cols as isize) as usize,
+                    )
+                } else if nr < rows as isize && nc >= 0 && nc < cols as isize {
+                    (nr as usize, nc as usize)
+                } else {
+                    continue;
+                };
+                edges.push((r * cols + c, nr * cols + nc));
+            }
+        }
+    }
+    Graph::new(rows * cols, &edges)
+}
+
+pub fn path(n: usize) -> Graph {
+    let edges: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+    Graph::new(n, &edges)
+}
+
+pub fn cycle(n: usize) -> Graph {
+    let mut edges: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+    if n > 2 {
+        edges.push((n - 1, 0));
+    }
+    Graph::new(n, &edges)
+}
+
+// Estrela com centro 0 e n - 1 folhas
+pub fn star(n: usize) -> Graph {
+    let edges: Vec<(usize, usize)> = (1..n).map(|v| (0, v)).collect();
+    Graph::new(n, &edges)
+}
+
+pub fn complete(n: usize) -> Graph {
+    let mut edges = vec![];
+    for v in 1..n {
+        for u in 0..v {
+            edges.push((u, v));
+        }
+    }
+    Graph::new(n, &edges)
+}
+
+// Bipartido completo K(a, b): vértices 0..a de um lado e a..a + b do outro
+pub fn complete_bipartite(a: usize, b: usize) -> Graph {
+    let mut edges = vec![];
+    for u in 0..a {
+        for v in a..a + b {
+            edges.push((u, v));
+        }
+    }
+    Graph::new(a + b, &edges)
+}
@@ -1,9 +1,13 @@
 use std::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use std::collections::BinaryHeap;
+use std::str::FromStr;
 
-use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::rngs::ThreadRng;
+use rand::seq::{index, SliceRandom};
+use rand::{thread_rng, Rng};
 
-use crate::graph::Graph;
+use crate::graph::{CsrGraph, Graph};
+use crate::verify::is_roman_dominating;
 
 pub struct Solution {
     labels: Vec<u8>,
@@ -29,22 +33,33 @@ impl Solution {
             modified: true,
         }
     }
+
+    // Solução a partir de uma rotulação pronta (por exemplo, levada de volta de um grafo
+    // reduzido); o fitness é o peso da rotulação
+    pub fn from_labels(labels: Vec<u8>) -> Self {
+        let fitness = labels.iter().map(|&l| l as usize).sum();
+        Solution::new(labels, Some(fitness))
+    }
+
+    pub fn get_labels(&self) -> &[u8] {
+        &self.labels
+    }
 }
 
 impl PartialOrd for Solution {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match (self.fitness, other.fitness) {
-            (Some(f1), Some(f2)) => Some(f1.cmp(&f2)),
-            (None, Some(_)) => Some(Ordering::Less), // Considerar None como menor
-            (Some(_), None) => Some(Ordering::Greater), // Considerar Some como maior
-            (None, None) => Some(Ordering::Equal),   // Ambos são None
-        }
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Solution {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap() // Aqui não precisa do unwrap_or
+        match (self.fitness, other.fitness) {
+            (Some(f1), Some(f2)) => f1.cmp(&f2),
+            (None, Some(_)) => Ordering::Less, // Considerar None como menor
+            (Some(_), None) => Ordering::Greater, // Considerar Some como maior
+            (None, None) => Ordering::Equal,   // Ambos são None
+        }
     }
 }
 
@@ -56,17 +71,295 @@ impl PartialEq for Solution {
 
 impl Eq for Solution {}
 
+// Busca local que remove redundâncias de uma rotulação viável sem perder a viabilidade:
+// - um 1 já dominado por um vizinho com rótulo 2 passa a 0;
+// - um 2 sem vizinho privado (vizinho com rótulo 0 dominado apenas por ele) passa a 0 quando
+//   ele próprio é dominado por outro 2, ou a 1 caso contrário;
+// - dois ou mais vizinhos com rótulo 1 de um vértice w passam a 0 e w passa a 2.
+// Cada movimento reduz o peso ou, com o mesmo peso, o número de rótulos 1, então a busca termina
+pub fn local_search(graph: &CsrGraph, solution: &mut Solution) {
+    let n = graph.get_num_vertices();
+    let labels = &mut solution.labels;
+    let neighbors = |v: usize| graph.get_neighbors(v).iter().map(|&u| u as usize);
+    // twos[v] = número de vizinhos de v com rótulo 2
+    let mut twos: Vec<usize> = (0..n)
+        .map(|v| neighbors(v).filter(|&u| labels[u] == 2).count())
+        .collect();
+    let set_label = |labels: &mut [u8], twos: &mut [usize], v: usize, label: u8| {
+        if labels[v] == 2 {
+            neighbors(v).for_each(|u| twos[u] -= 1);
+        }
+        if label == 2 {
+            neighbors(v).for_each(|u| twos[u] += 1);
+        }
+        labels[v] = label;
+    };
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for v in 0..n {
+            match labels[v] {
+                1 if twos[v] > 0 => {
+                    set_label(labels, &mut twos, v, 0);
+                    changed = true;
+                }
+                2 if !neighbors(v).any(|u| labels[u] == 0 && twos[u] == 1) => {
+                    let label = if twos[v] > 0 { 0 } else { 1 };
+                    set_label(labels, &mut twos, v, label);
+                    changed = true;
+                }
+                _ => {}
+            }
+        }
+        for w in 0..n {
+            if labels[w] == 2 {
+                continue;
+            }
+            let ones: Vec<usize> = neighbors(w).filter(|&u| labels[u] == 1).collect();
+            if ones.len() >= 2 {
+                set_label(labels, &mut twos, w, 2);
+                for u in ones {
+                    set_label(labels, &mut twos, u, 0);
+                }
+                changed = true;
+            }
+        }
+    }
+
+    solution.fitness = Some(labels.iter().map(|&l| l as usize).sum());
+}
+
+// Operadores de mutação; a cada mutação um dos operadores habilitados é sorteado
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mutation {
+    // Um vértice aleatório recebe outro rótulo aleatório
+    RandomRelabel,
+    // Um vértice com rótulo 2 passa a ter rótulo 1
+    Demote,
+    // Um vértice aleatório recebe 2 e seus vizinhos recebem 0
+    PromoteAndClear,
+    // Um vértice com rótulo 2 troca de rótulo com um vizinho que não tem 2
+    SwapToNeighbor,
+}
+
+impl Mutation {
+    pub const ALL: [Mutation; 4] = [
+        Mutation::RandomRelabel,
+        Mutation::Demote,
+        Mutation::PromoteAndClear,
+        Mutation::SwapToNeighbor,
+    ];
+}
+
+impl FromStr for Mutation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "relabel" => Ok(Mutation::RandomRelabel),
+            "demote" => Ok(Mutation::Demote),
+            "promote" => Ok(Mutation::PromoteAndClear),
+            "swap" => Ok(Mutation::SwapToNeighbor),
+            _ => Err(format!(
+                "operador de mutação desconhecido '{}' (use relabel, demote, promote ou swap)",
+                s
+            )),
+        }
+    }
+}
+
+// Operadores de cruzamento
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Crossover {
+    // Cada gene vem de um dos pais com a mesma probabilidade
+    Uniform,
+    // k pontos de corte; os segmentos entre cortes consecutivos alternam entre os pais
+    KPoint(usize),
+    // Uniforme, mas cada gene vem do pai de menor peso com probabilidade proporcional ao
+    // peso do outro pai
+    FitnessBiasedUniform,
+    // Uma região conexa, obtida por BFS a partir de um vértice aleatório até um tamanho
+    // aleatório, vem de um dos pais e o restante do outro, preservando a estrutura local de
+    // dominação de cada um
+    BfsRegion,
+}
+
+impl FromStr for Crossover {
+    type Err = String;
+
+    // Aceita "uniform", "biased", "region", "kpoint" (dois cortes) e "kpoint:k"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            None if s == "uniform" => Ok(Crossover::Uniform),
+            None if s == "biased" => Ok(Crossover::FitnessBiasedUniform),
+            None if s == "region" => Ok(Crossover::BfsRegion),
+            None if s == "kpoint" => Ok(Crossover::KPoint(2)),
+            Some(("kpoint", k)) => match k.parse() {
+                Ok(k) if k > 0 => Ok(Crossover::KPoint(k)),
+                _ => Err(format!("número de cortes inválido '{}'", k)),
+            },
+            _ => Err(format!(
+                "operador de cruzamento desconhecido '{}' (use uniform, kpoint[:k], biased ou region)",
+                s
+            )),
+        }
+    }
+}
+
+// Estratégias para tornar viável uma rotulação que deixa vértices com rótulo 0 sem vizinho
+// com rótulo 2
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Repair {
+    // Cada vértice não dominado recebe 1
+    Naive,
+    // Promove a 2 o vértice que domina mais vértices ainda não dominados, enquanto isso não
+    // for mais caro que rotulá-los com 1
+    Greedy,
+}
+
+impl FromStr for Repair {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "naive" => Ok(Repair::Naive),
+            "greedy" => Ok(Repair::Greedy),
+            _ => Err(format!(
+                "estratégia de reparo desconhecida '{}' (use naive ou greedy)",
+                s
+            )),
+        }
+    }
+}
+
+// Forma de resolver cada componente conexa do grafo
+enum ComponentSolver {
+    // Rotulação ótima conhecida em forma fechada
+    Fixed(Vec<u8>),
+    Ga(Box<RomanDominationGA>),
+}
+
+struct Component {
+    vertices: Vec<usize>,
+    solver: ComponentSolver,
+}
+
+// Rotulação ótima de componentes triviais: um vértice isolado recebe 1 e uma componente com
+// um vértice adjacente a todos os outros (K2, estrelas, grafos completos) tem peso 2
+fn closed_form_labels(graph: &Graph, vertices: &[usize]) -> Option<Vec<u8>> {
+    if vertices.len() == 1 {
+        return Some(vec![1]);
+    }
+    let center = vertices
+        .iter()
+        .position(|&v| graph.get_vertex_degree(v) == vertices.len() - 1)?;
+    let mut labels = vec![0; vertices.len()];
+    labels[center] = 2;
+    Some(labels)
+}
+
 pub struct RomanDominationGA {
     graph: Graph,
+    // Forma congelada do grafo, usada nas avaliações de viabilidade
+    frozen: CsrGraph,
     population_size: usize,
+    mutations: Vec<Mutation>,
+    crossover: Crossover,
+    // Quando verdadeiro, cada cruzamento gera também o filho complementar
+    two_children: bool,
+    repair: Repair,
+    // Aplica local_search à população inicial e à melhor solução final (desligado por padrão,
+    // como no AG original)
+    local_search: bool,
+    // A dominação romana se decompõe por componentes conexas: quando o grafo é desconexo ou
+    // tem solução em forma fechada, cada componente é resolvida separadamente
+    components: Vec<Component>,
+    // Com decompose falso o grafo inteiro é resolvido por um único AG, como no AG original
+    decompose: bool,
 }
 
 impl RomanDominationGA {
     pub fn new(graph: Graph, population_size: Option<usize>) -> Self {
-        let population_size = population_size.unwrap_or(graph.get_num_vertices() / 2);
+        let n = graph.get_num_vertices();
+        let population_size = population_size.unwrap_or(n / 2);
+
+        let vertex_sets = graph.get_connected_components();
+        let mut components = vec![];
+        let decompose = vertex_sets.len() > 1
+            || vertex_sets
+                .first()
+                .is_some_and(|vertices| closed_form_labels(&graph, vertices).is_some());
+        if decompose {
+            for vertices in vertex_sets {
+                let solver = match closed_form_labels(&graph, &vertices) {
+                    Some(labels) => ComponentSolver::Fixed(labels),
+                    None => {
+                        // A população é dividida proporcionalmente ao tamanho da componente
+                        let component_population = (population_size * vertices.len() / n).max(3);
+                        ComponentSolver::Ga(Box::new(RomanDominationGA::new(
+                            graph.induced_subgraph(&vertices),
+                            Some(component_population),
+                        )))
+                    }
+                };
+                components.push(Component { vertices, solver });
+            }
+        }
+
         RomanDominationGA {
+            frozen: graph.freeze(),
             graph,
             population_size,
+            mutations: Mutation::ALL.to_vec(),
+            crossover: Crossover::KPoint(2),
+            two_children: false,
+            repair: Repair::Naive,
+            local_search: false,
+            components,
+            decompose: true,
+        }
+    }
+
+    pub fn set_decompose(&mut self, enabled: bool) {
+        self.decompose = enabled;
+    }
+
+    pub fn set_crossover(&mut self, crossover: Crossover, two_children: bool) {
+        self.crossover = crossover;
+        self.two_children = two_children;
+        for component in &mut self.components {
+            if let ComponentSolver::Ga(ga) = &mut component.solver {
+                ga.set_crossover(crossover, two_children);
+            }
+        }
+    }
+
+    pub fn set_repair(&mut self, repair: Repair) {
+        self.repair = repair;
+        for component in &mut self.components {
+            if let ComponentSolver::Ga(ga) = &mut component.solver {
+                ga.set_repair(repair);
+            }
+        }
+    }
+
+    pub fn set_local_search(&mut self, enabled: bool) {
+        self.local_search = enabled;
+        for component in &mut self.components {
+            if let ComponentSolver::Ga(ga) = &mut component.solver {
+                ga.set_local_search(enabled);
+            }
+        }
+    }
+
+    // Define os operadores sorteados nas mutações (por padrão, todos)
+    pub fn set_mutations(&mut self, mutations: &[Mutation]) {
+        self.mutations = mutations.to_vec();
+        for component in &mut self.components {
+            if let ComponentSolver::Ga(ga) = &mut component.solver {
+                ga.set_mutations(mutations);
+            }
         }
     }
 
@@ -149,46 +442,82 @@ impl RomanDominationGA {
     }
 
     fn is_feasible(&self, solution: &Solution) -> bool {
-        for vertex in 0..self.graph.get_num_vertices() {
-            if solution.labels[vertex] == 0
-                && !self
-                    .graph
-                    .get_neighbors(vertex)
-                    .iter()
-                    .any(|&neighbor| solution.labels[neighbor] == 2)
-            {
-                return false;
-            }
-        }
-        true
+        is_roman_dominating(&self.frozen, &solution.labels)
     }
 
     fn make_feasible(&self, solution: &mut Solution) {
-        let mut was_modified = false;
+        let n = self.frozen.get_num_vertices();
+        let mut undominated: Vec<bool> = (0..n)
+            .map(|vertex| {
+                solution.labels[vertex] == 0
+                    && !self
+                        .frozen
+                        .get_neighbors(vertex)
+                        .iter()
+                        .any(|&neighbor| solution.labels[neighbor as usize] == 2)
+            })
+            .collect();
+        if !undominated.contains(&true) {
+            return;
+        }
 
-        for vertex in 0..self.graph.get_num_vertices() {
-            if solution.labels[vertex] == 0
-                && !self
-                    .graph
-                    .get_neighbors(vertex)
-                    .iter()
-                    .any(|&neighbor| solution.labels[neighbor] == 2)
-            {
-                solution.labels[vertex] = 1;
-                was_modified = true;
+        if self.repair == Repair::Greedy {
+            self.greedy_repair(&mut solution.labels, &mut undominated);
+        }
+        for (label, &undominated) in solution.labels.iter_mut().zip(&undominated) {
+            if undominated {
+                *label = 1;
             }
         }
 
-        if was_modified {
-            solution.modified = true;
-            solution.fitness = None;
+        solution.modified = true;
+        solution.fitness = None;
+    }
+
+    // Promove a 2 o vértice de maior ganho (número de vértices não dominados em sua vizinhança
+    // fechada) enquanto o custo da promoção não supera o ganho. Os ganhos só diminuem, então a
+    // fila de prioridade é atualizada de forma preguiçosa
+    fn greedy_repair(&self, labels: &mut [u8], undominated: &mut [bool]) {
+        let closed_neighborhood = |v: usize| {
+            std::iter::once(v).chain(self.frozen.get_neighbors(v).iter().map(|&u| u as usize))
+        };
+        let gain = |v: usize, undominated: &[bool]| {
+            closed_neighborhood(v).filter(|&u| undominated[u]).count()
+        };
+
+        let mut heap: BinaryHeap<(usize, usize)> = (0..undominated.len())
+            .filter(|&v| undominated[v])
+            .flat_map(closed_neighborhood)
+            .filter(|&w| labels[w] != 2)
+            .map(|w| (gain(w, undominated), w))
+            .collect();
+
+        while let Some((old_gain, w)) = heap.pop() {
+            if labels[w] == 2 {
+                continue;
+            }
+            let current_gain = gain(w, undominated);
+            if current_gain < old_gain {
+                if current_gain > 0 {
+                    heap.push((current_gain, w));
+                }
+                continue;
+            }
+            // Custo de promover w a 2 contra rotular com 1 os vértices que ele dominaria
+            if 2 - labels[w] as usize > current_gain {
+                break;
+            }
+            labels[w] = 2;
+            for u in closed_neighborhood(w) {
+                undominated[u] = false;
+            }
         }
     }
 
     fn tournament_selection(
         &mut self,
         tournament_size: usize,
-        population: &mut Vec<Solution>,
+        population: &mut [Solution],
     ) -> Vec<Solution> {
         let mut selected = Vec::with_capacity(population.len());
         let mut rng = thread_rng();
@@ -218,19 +547,132 @@ impl RomanDominationGA {
         selected
     }
 
-    fn crossover(&self, parent_a: &Solution, parent_b: &Solution) -> Solution {
-        let mut rng = thread_rng();
-        let (r1, r2) = {
-            let mut indices: Vec<usize> = (0..self.graph.get_num_vertices()).collect();
-            indices.shuffle(&mut rng);
-            (indices[0], indices[1])
+    // Devolve um filho, ou dois com two_children; o segundo filho recebe cada gene do pai que
+    // não foi escolhido para o primeiro
+    fn crossover(
+        &self,
+        parent_a: &Solution,
+        parent_b: &Solution,
+        rng: &mut ThreadRng,
+    ) -> Vec<Solution> {
+        let n = self.frozen.get_num_vertices();
+        // from_b[v] indica que o primeiro filho herda o gene v de parent_b
+        let from_b: Vec<bool> = match self.crossover {
+            Crossover::Uniform => (0..n).map(|_| rng.gen_bool(0.5)).collect(),
+            Crossover::FitnessBiasedUniform => {
+                let fitness_a = parent_a.fitness.unwrap_or_default() as f64;
+                let fitness_b = parent_b.fitness.unwrap_or_default() as f64;
+                let p_b = if fitness_a + fitness_b > 0.0 {
+                    fitness_a / (fitness_a + fitness_b)
+                } else {
+                    0.5
+                };
+                (0..n).map(|_| rng.gen_bool(p_b)).collect()
+            }
+            Crossover::KPoint(k) => {
+                let mut cuts = index::sample(rng, n, k.min(n)).into_vec();
+                cuts.sort_unstable();
+                let mut from_b = vec![false; n];
+                for pair in cuts.chunks(2) {
+                    let end = pair.get(1).copied().unwrap_or(n);
+                    from_b[pair[0]..end].fill(true);
+                }
+                from_b
+            }
+            Crossover::BfsRegion => {
+                let mut from_b = vec![false; n];
+                if n > 0 {
+                    let size = rng.gen_range(1..=n.max(2) - 1);
+                    let root = rng.gen_range(0..n);
+                    let mut region = vec![root];
+                    from_b[root] = true;
+                    let mut i = 0;
+                    while i < region.len() && region.len() < size {
+                        for &u in self.frozen.get_neighbors(region[i]) {
+                            let u = u as usize;
+                            if !from_b[u] && region.len() < size {
+                                from_b[u] = true;
+                                region.push(u);
+                            }
+                        }
+                        i += 1;
+                    }
+                }
+                from_b
+            }
         };
 
-        let (r1, r2) = (r1.min(r2), r1.max(r2));
-        let mut child_labels = parent_a.labels.clone();
-        child_labels[r1..r2].copy_from_slice(&parent_b.labels[r1..r2]);
+        let child = |take_b: bool| {
+            let labels = (0..n)
+                .map(|v| {
+                    if from_b[v] == take_b {
+                        parent_b.labels[v]
+                    } else {
+                        parent_a.labels[v]
+                    }
+                })
+                .collect();
+            Solution::new(labels, None)
+        };
+        if self.two_children {
+            vec![child(true), child(false)]
+        } else {
+            vec![child(true)]
+        }
+    }
 
-        Solution::new(child_labels, None)
+    fn mutate(&self, solution: &mut Solution, rng: &mut ThreadRng) {
+        let n = self.frozen.get_num_vertices();
+        if n == 0 {
+            return;
+        }
+        let Some(&mutation) = self.mutations.choose(rng) else {
+            return;
+        };
+        let labels = &mut solution.labels;
+        let random_2 = |labels: &[u8], rng: &mut ThreadRng| {
+            let twos: Vec<usize> = (0..n).filter(|&v| labels[v] == 2).collect();
+            twos.choose(rng).copied()
+        };
+
+        match mutation {
+            Mutation::RandomRelabel => {
+                let v = rng.gen_range(0..n);
+                labels[v] = (labels[v] + rng.gen_range(1..=2)) % 3;
+            }
+            Mutation::Demote => {
+                let Some(v) = random_2(labels, rng) else {
+                    return;
+                };
+                labels[v] = 1;
+            }
+            Mutation::PromoteAndClear => {
+                let v = rng.gen_range(0..n);
+                labels[v] = 2;
+                for &u in self.frozen.get_neighbors(v) {
+                    labels[u as usize] = 0;
+                }
+            }
+            Mutation::SwapToNeighbor => {
+                let Some(v) = random_2(labels, rng) else {
+                    return;
+                };
+                // Trocar com um vizinho que também tem 2 não mudaria a rotulação
+                let candidates: Vec<usize> = self
+                    .frozen
+                    .get_neighbors(v)
+                    .iter()
+                    .map(|&u| u as usize)
+                    .filter(|&u| labels[u] != 2)
+                    .collect();
+                let Some(&u) = candidates.choose(rng) else {
+                    return;
+                };
+                labels.swap(v, u);
+            }
+        }
+        solution.modified = true;
+        solution.fitness = None;
     }
 
     pub fn run(
@@ -239,11 +681,64 @@ impl RomanDominationGA {
         max_stagnant: usize,
         tournament_size: usize,
         crossover_probability: f32,
+        mutation_rate: f32,
+    ) -> Solution {
+        // O grafo vazio (por exemplo, "?" em graph6 ou "p edge 0 0" em DIMACS) tem γR = 0 e
+        // nenhuma população a evoluir
+        if self.graph.get_num_vertices() == 0 {
+            return Solution::new(vec![], Some(0));
+        }
+        if self.components.is_empty() || !self.decompose {
+            return self.run_connected(
+                max_generations,
+                max_stagnant,
+                tournament_size,
+                crossover_probability,
+                mutation_rate,
+            );
+        }
+
+        // Resolve cada componente e junta as rotulações numa única solução
+        let mut labels = vec![0; self.graph.get_num_vertices()];
+        for component in &mut self.components {
+            let component_labels = match &mut component.solver {
+                ComponentSolver::Fixed(labels) => labels.clone(),
+                ComponentSolver::Ga(ga) => {
+                    ga.run(
+                        max_generations,
+                        max_stagnant,
+                        tournament_size,
+                        crossover_probability,
+                        mutation_rate,
+                    )
+                    .labels
+                }
+            };
+            for (&v, &label) in component.vertices.iter().zip(&component_labels) {
+                labels[v] = label;
+            }
+        }
+
+        let fitness = labels.iter().map(|&x| x as usize).sum();
+        Solution::new(labels, Some(fitness))
+    }
+
+    fn run_connected(
+        &mut self,
+        max_generations: usize,
+        max_stagnant: usize,
+        tournament_size: usize,
+        crossover_probability: f32,
+        mutation_rate: f32,
     ) -> Solution {
         let mut population: Vec<Solution> = self.generate_initial_population();
+        let mut rng = thread_rng();
 
         for solution in &mut population {
             self.evaluate_fitness(solution);
+            if self.local_search {
+                local_search(&self.frozen, solution);
+            }
         }
 
         let mut best_solution = population
@@ -265,12 +760,17 @@ impl RomanDominationGA {
                 if i + 1 < intermediate_pop.len() {
                     new_population.push(intermediate_pop[i + 1].clone());
 
-                    if rand::random::<f32>() < crossover_probability {
-                        let mut child =
-                            self.crossover(&intermediate_pop[i], &intermediate_pop[i + 1]);
-                        self.evaluate_fitness(&mut child);
-                        if new_population.len() < self.population_size {
-                            new_population.push(child);
+                    if rng.gen::<f32>() < crossover_probability {
+                        let children = self.crossover(
+                            &intermediate_pop[i],
+                            &intermediate_pop[i + 1],
+                            &mut rng,
+                        );
+                        for mut child in children {
+                            self.evaluate_fitness(&mut child);
+                            if new_population.len() < self.population_size {
+                                new_population.push(child);
+                            }
                         }
                     }
                 }
@@ -281,7 +781,11 @@ impl RomanDominationGA {
                 }
             }
 
+            // Cada indivíduo da nova população sofre uma mutação com probabilidade mutation_rate
             for solution in &mut new_population {
+                if rng.gen::<f32>() < mutation_rate {
+                    self.mutate(solution, &mut rng);
+                }
                 if solution.modified || solution.fitness.is_none() {
                     self.evaluate_fitness(solution);
                 }
@@ -310,6 +814,9 @@ impl RomanDominationGA {
             generation += 1;
         }
 
+        if self.local_search {
+            local_search(&self.frozen, &mut best_solution);
+        }
         best_solution
     }
 }
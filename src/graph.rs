@@ -1,25 +1,79 @@
 use std::{
-    collections::HashSet,
-    fs::File,
-    io::{self, BufRead},
-    usize,
+    collections::{HashMap, HashSet},
+    io,
 };
 
 use rand::seq::IteratorRandom;
 
+mod csr;
+mod dimacs;
+mod dot;
+mod edge_list;
+mod graph6;
+mod graphml;
+mod harwell_boeing;
+mod matrix_market;
+mod metis;
+mod pajek;
+mod stats;
+
+pub use csr::CsrGraph;
+pub use edge_list::{EdgeListMode, GraphParseError, GraphParseErrorKind, VertexNames};
+pub use graph6::Graph6Reader;
+pub use stats::GraphStats;
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
 #[derive(Clone)]
 pub struct Graph {
     adjacency_list: Vec<Vec<usize>>, // Lista de adjacências com nós como strings
+    // Arestas repetidas e laços descartados para manter o grafo simples
+    dropped_duplicates: usize,
+    dropped_self_loops: usize,
+    // Nomes originais dos vértices quando os IDs foram compactados
+    vertex_names: Option<VertexNames>,
 }
 
 impl Graph {
+    // Constrói o grafo de uma vez: as arestas são inseridas sem verificação e as repetidas são
+    // removidas ao final, em O(n + m), mantendo a primeira ocorrência de cada vizinho como
+    // faria add_edge
     pub fn new(num_vertices: usize, edges: &[(usize, usize)]) -> Self {
-        let mut adjacency_list = vec![vec![]; num_vertices];
+        let mut g = Graph {
+            adjacency_list: vec![vec![]; num_vertices],
+            dropped_duplicates: 0,
+            dropped_self_loops: 0,
+            vertex_names: None,
+        };
         for &(u, v) in edges {
-            adjacency_list[u].push(v);
-            adjacency_list[v].push(u);
+            g.add_vertex(u.max(v));
+            if u == v {
+                g.dropped_self_loops += 1;
+                continue;
+            }
+            g.adjacency_list[u].push(v);
+            g.adjacency_list[v].push(u);
         }
-        Graph { adjacency_list }
+        g.remove_duplicate_edges();
+        g
+    }
+
+    fn remove_duplicate_edges(&mut self) {
+        // last_seen[v] = último vértice em cuja lista v apareceu
+        let mut last_seen = vec![usize::MAX; self.adjacency_list.len()];
+        let mut removed = 0;
+        for (u, neighbors) in self.adjacency_list.iter_mut().enumerate() {
+            neighbors.retain(|&v| {
+                let duplicate = last_seen[v] == u;
+                last_seen[v] = u;
+                removed += duplicate as usize;
+                !duplicate
+            });
+        }
+        // Cada aresta repetida aparece uma vez na lista de cada extremidade
+        self.dropped_duplicates += removed / 2;
     }
 
     pub fn get_num_vertices(&self) -> usize {
@@ -40,14 +94,47 @@ impl Graph {
         }
     }
 
+    // Mantém o grafo simples: laços e arestas já existentes (em qualquer sentido) são
+    // descartados e contabilizados. A verificação custa O(grau); para construir um grafo
+    // inteiro, Graph::new é linear
     pub fn add_edge(&mut self, u: usize, v: usize) {
         self.add_vertex(u);
         self.add_vertex(v);
 
+        if u == v {
+            self.dropped_self_loops += 1;
+            return;
+        }
+        if self.adjacency_list[u].contains(&v) {
+            self.dropped_duplicates += 1;
+            return;
+        }
+
         self.adjacency_list[u].push(v);
         self.adjacency_list[v].push(u);
     }
 
+    pub fn get_vertex_names(&self) -> Option<&VertexNames> {
+        self.vertex_names.as_ref()
+    }
+
+    // Nome original do vértice, ou o próprio índice quando não houve compactação
+    pub fn get_vertex_name(&self, vertex: usize) -> String {
+        self.vertex_names
+            .as_ref()
+            .and_then(|names| names.get_name(vertex))
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| vertex.to_string())
+    }
+
+    pub fn get_dropped_duplicates(&self) -> usize {
+        self.dropped_duplicates
+    }
+
+    pub fn get_dropped_self_loops(&self) -> usize {
+        self.dropped_self_loops
+    }
+
     pub fn h1(&self) -> Vec<u8> {
         let mut f: Vec<u8> = vec![0; self.adjacency_list.len()];
         let mut unvisited: HashSet<usize> = (0..self.adjacency_list.len()).collect();
@@ -204,29 +291,41 @@ impl Graph {
         f
     }
 
+    // Lê a lista de arestas no modo tolerante (comentários e coluna de peso são aceitos);
+    // linhas inválidas geram erro em vez de serem ignoradas
     pub fn from_file(file_path: String) -> io::Result<Self> {
-        let file = File::open(&file_path)?;
-        let reader = io::BufReader::new(file);
-        let mut g = Graph::new(0, &[]);
-
-        for line in reader.lines() {
-            let line = line?;
-            let vertices: Vec<usize> = line
-                .split_whitespace()
-                .filter_map(|s| s.parse::<usize>().ok())
-                .collect();
+        Ok(Graph::from_edge_list(file_path, EdgeListMode::Lenient)?)
+    }
 
-            if vertices.len() == 2 {
-                let (u, v) = (vertices[0], vertices[1]);
-                g.add_vertex(u);
-                g.add_vertex(v);
-                if u != v {
-                    g.add_edge(u, v);
+    // Subgrafo induzido pelos vértices informados; o vértice vertices[i] vira o vértice i
+    pub fn induced_subgraph(&self, vertices: &[usize]) -> Graph {
+        let local: HashMap<usize, usize> =
+            vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+
+        let mut edges = vec![];
+        for (i, &u) in vertices.iter().enumerate() {
+            for &v in self.get_neighbors(u) {
+                if let Some(&j) = local.get(&v) {
+                    if i < j {
+                        edges.push((i, j));
+                    }
                 }
             }
         }
+        Graph::new(vertices.len(), &edges)
+    }
 
-        Ok(g)
+    pub fn get_edges(&self) -> Vec<(usize, usize)> {
+        let mut edges = vec![];
+        for (u, neighbors) in self.adjacency_list.iter().enumerate() {
+            for &v in neighbors {
+                if u < v {
+                    edges.push((u, v));
+                }
+            }
+        }
+        edges.sort_unstable();
+        edges
     }
 
     pub fn get_graph_size(&self) -> usize {
@@ -0,0 +1,54 @@
+use super::Graph;
+
+// Representação imutável do grafo em CSR (compressed sparse row): os vizinhos do vértice v
+// ficam em neighbors[offsets[v]..offsets[v + 1]], num único vetor contíguo
+#[derive(Clone)]
+pub struct CsrGraph {
+    offsets: Vec<usize>,
+    neighbors: Vec<u32>,
+}
+
+impl CsrGraph {
+    pub fn get_num_vertices(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn get_neighbors(&self, vertex: usize) -> &[u32] {
+        &self.neighbors[self.offsets[vertex]..self.offsets[vertex + 1]]
+    }
+
+    pub fn get_vertex_degree(&self, vertex: usize) -> usize {
+        self.offsets[vertex + 1] - self.offsets[vertex]
+    }
+
+    pub fn get_graph_size(&self) -> usize {
+        self.neighbors.len() / 2
+    }
+}
+
+impl From<&Graph> for CsrGraph {
+    fn from(graph: &Graph) -> Self {
+        let n = graph.get_num_vertices();
+        assert!(
+            n <= u32::MAX as usize,
+            "o grafo tem vértices demais para a representação CSR"
+        );
+
+        let mut offsets = Vec::with_capacity(n + 1);
+        let mut neighbors = Vec::with_capacity(graph.get_graph_size() * 2);
+        offsets.push(0);
+        for v in 0..n {
+            neighbors.extend(graph.get_neighbors(v).iter().map(|&u| u as u32));
+            offsets.push(neighbors.len());
+        }
+
+        CsrGraph { offsets, neighbors }
+    }
+}
+
+impl Graph {
+    // Congela o grafo na representação CSR usada durante a execução do AG
+    pub fn freeze(&self) -> CsrGraph {
+        CsrGraph::from(self)
+    }
+}
@@ -0,0 +1,98 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufWriter, Write},
+};
+
+use super::{invalid_data, Graph};
+
+impl Graph {
+    // Lê um grafo no formato DIMACS (.col/.clq): linhas "c" de comentário,
+    // uma linha "p edge n m" e arestas "e u v" com vértices numerados a partir de 1
+    pub fn from_dimacs(file_path: String) -> io::Result<Self> {
+        let file = File::open(&file_path)?;
+        let reader = io::BufReader::new(file);
+        let mut num_vertices: Option<usize> = None;
+        let mut edges: Vec<(usize, usize)> = vec![];
+
+        for (line_number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let line_number = line_number + 1;
+
+            match tokens.first() {
+                None | Some(&"c") => {}
+                Some(&"p") => {
+                    if num_vertices.is_some() {
+                        return Err(invalid_data(format!(
+                            "{}:{}: linha 'p' repetida",
+                            file_path, line_number
+                        )));
+                    }
+                    let n = match tokens.as_slice() {
+                        [_, "edge" | "edges" | "col" | "clq", n, _] => n.parse::<usize>().ok(),
+                        _ => None,
+                    };
+                    match n {
+                        Some(n) => num_vertices = Some(n),
+                        None => {
+                            return Err(invalid_data(format!(
+                                "{}:{}: linha 'p' inválida: '{}'",
+                                file_path, line_number, line
+                            )))
+                        }
+                    }
+                }
+                Some(&"e") => {
+                    let n = num_vertices.ok_or_else(|| {
+                        invalid_data(format!(
+                            "{}:{}: aresta antes da linha 'p'",
+                            file_path, line_number
+                        ))
+                    })?;
+                    let vertex = |s: &str| s.parse::<usize>().ok().filter(|&k| k >= 1 && k <= n);
+                    match tokens.as_slice() {
+                        [_, u, v] | [_, u, v, _] => match (vertex(u), vertex(v)) {
+                            (Some(u), Some(v)) => edges.push((u - 1, v - 1)),
+                            _ => {
+                                return Err(invalid_data(format!(
+                                    "{}:{}: aresta inválida: '{}'",
+                                    file_path, line_number, line
+                                )))
+                            }
+                        },
+                        _ => {
+                            return Err(invalid_data(format!(
+                                "{}:{}: aresta inválida: '{}'",
+                                file_path, line_number, line
+                            )))
+                        }
+                    }
+                }
+                // Linhas "n" (pesos de vértices) e similares não afetam a estrutura
+                Some(&"n") => {}
+                Some(_) => {
+                    return Err(invalid_data(format!(
+                        "{}:{}: linha DIMACS desconhecida: '{}'",
+                        file_path, line_number, line
+                    )))
+                }
+            }
+        }
+
+        let num_vertices = num_vertices
+            .ok_or_else(|| invalid_data(format!("{}: linha 'p edge n m' ausente", file_path)))?;
+        Ok(Graph::new(num_vertices, &edges))
+    }
+
+    // Escreve o grafo no formato DIMACS, numerando os vértices a partir de 1
+    pub fn write_dimacs(&self, file_path: String) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&file_path)?);
+        let edges = self.get_edges();
+
+        writeln!(writer, "p edge {} {}", self.get_num_vertices(), edges.len())?;
+        for (u, v) in edges {
+            writeln!(writer, "e {} {}", u + 1, v + 1)?;
+        }
+        writer.flush()
+    }
+}
@@ -0,0 +1,47 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+};
+
+use super::Graph;
+
+// Cores usadas para os rótulos 0, 1 e 2 de uma função de dominação romana
+pub(super) const LABEL_COLORS: [&str; 3] = ["#ffffff", "#9ecae1", "#d62728"];
+
+fn escape(name: &str) -> String {
+    name.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl Graph {
+    // Escreve o grafo no formato DOT do Graphviz. Quando os rótulos de uma solução são
+    // informados, cada vértice recebe o atributo "roman" com o rótulo e a cor correspondente
+    pub fn write_dot(&self, file_path: String, labels: Option<&[u8]>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&file_path)?);
+
+        writeln!(writer, "graph G {{")?;
+        writeln!(
+            writer,
+            "    node [style=filled, fillcolor=\"{}\"];",
+            LABEL_COLORS[0]
+        )?;
+        for v in 0..self.get_num_vertices() {
+            let name = escape(&self.get_vertex_name(v));
+            match labels.map(|labels| labels[v]) {
+                Some(label) => writeln!(
+                    writer,
+                    "    {} [label=\"{}\", roman={}, fillcolor=\"{}\"];",
+                    v,
+                    name,
+                    label,
+                    LABEL_COLORS[(label as usize).min(2)]
+                )?,
+                None => writeln!(writer, "    {} [label=\"{}\"];", v, name)?,
+            }
+        }
+        for (u, v) in self.get_edges() {
+            writeln!(writer, "    {} -- {};", u, v)?;
+        }
+        writeln!(writer, "}}")?;
+        writer.flush()
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt,
+    fs::File,
+    io::{self, BufRead},
+};
+
+use super::Graph;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EdgeListMode {
+    // Toda linha não vazia deve conter exatamente dois vértices "u v"
+    Strict,
+    // Aceita comentários iniciados por '#' ou '%' e ignora uma terceira coluna de peso
+    Lenient,
+}
+
+#[derive(Debug)]
+pub enum GraphParseErrorKind {
+    Io(io::Error),
+    WrongTokenCount {
+        expected: &'static str,
+        found: usize,
+    },
+    InvalidVertex(String),
+    InvalidWeight(String),
+}
+
+#[derive(Debug)]
+pub struct GraphParseError {
+    pub file: String,
+    pub line: Option<usize>,
+    pub kind: GraphParseErrorKind,
+}
+
+impl fmt::Display for GraphParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: ", self.file, line)?,
+            None => write!(f, "{}: ", self.file)?,
+        }
+        match &self.kind {
+            GraphParseErrorKind::Io(e) => write!(f, "erro de leitura: {}", e),
+            GraphParseErrorKind::WrongTokenCount { expected, found } => {
+                write!(f, "esperados {} campos, encontrados {}", expected, found)
+            }
+            GraphParseErrorKind::InvalidVertex(token) => write!(
+                f,
+                "vértice inválido '{}' (esperado um inteiro não negativo)",
+                token
+            ),
+            GraphParseErrorKind::InvalidWeight(token) => {
+                write!(f, "peso inválido '{}' (esperado um número)", token)
+            }
+        }
+    }
+}
+
+impl Error for GraphParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            GraphParseErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<GraphParseError> for io::Error {
+    fn from(e: GraphParseError) -> Self {
+        match e.kind {
+            GraphParseErrorKind::Io(io_error) => io_error,
+            _ => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+// Tabela de nomes originais dos vértices, usada quando os IDs são compactados
+#[derive(Clone, Debug, Default)]
+pub struct VertexNames {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+impl VertexNames {
+    // Devolve o índice denso do nome, criando um novo se ele ainda não foi visto
+    fn intern(&mut self, name: &str) -> usize {
+        if let Some(&v) = self.index.get(name) {
+            return v;
+        }
+        let v = self.names.len();
+        self.names.push(name.to_string());
+        self.index.insert(name.to_string(), v);
+        v
+    }
+
+    pub fn get_name(&self, vertex: usize) -> Option<&str> {
+        self.names.get(vertex).map(|name| name.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn get_index(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+}
+
+impl Graph {
+    // Lê uma lista de arestas "u v", uma por linha. Diferente do comportamento antigo do
+    // from_file, nenhuma linha é descartada em silêncio: qualquer linha que não possa ser
+    // interpretada gera um erro com o arquivo e o número da linha
+    pub fn from_edge_list(file_path: String, mode: EdgeListMode) -> Result<Self, GraphParseError> {
+        let mut edges = vec![];
+        read_edge_list(
+            &file_path,
+            mode,
+            |token| token.parse::<usize>().ok(),
+            |u, v| edges.push((u, v)),
+        )?;
+
+        let num_vertices = edges.iter().map(|&(u, v)| u.max(v) + 1).max().unwrap_or(0);
+        Ok(Graph::new(num_vertices, &edges))
+    }
+
+    // Igual a from_edge_list, mas aceita nomes arbitrários (inteiros esparsos ou texto) e os
+    // compacta em índices 0..n na ordem em que aparecem; os nomes originais ficam no grafo
+    pub fn from_edge_list_compact(
+        file_path: String,
+        mode: EdgeListMode,
+    ) -> Result<Self, GraphParseError> {
+        let mut names = VertexNames::default();
+        let mut edges = vec![];
+        read_edge_list(
+            &file_path,
+            mode,
+            |token| Some(names.intern(token)),
+            |u, v| edges.push((u, v)),
+        )?;
+
+        let mut g = Graph::new(names.names.len(), &edges);
+        g.vertex_names = Some(names);
+        Ok(g)
+    }
+}
+
+fn read_edge_list(
+    file_path: &str,
+    mode: EdgeListMode,
+    mut vertex_id: impl FnMut(&str) -> Option<usize>,
+    mut add_edge: impl FnMut(usize, usize),
+) -> Result<(), GraphParseError> {
+    let error = |line: Option<usize>, kind: GraphParseErrorKind| GraphParseError {
+        file: file_path.to_string(),
+        line,
+        kind,
+    };
+
+    let file = File::open(file_path).map_err(|e| error(None, GraphParseErrorKind::Io(e)))?;
+    let reader = io::BufReader::new(file);
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line_number = line_number + 1;
+        let line = line.map_err(|e| error(Some(line_number), GraphParseErrorKind::Io(e)))?;
+
+        let content = match mode {
+            EdgeListMode::Strict => line.as_str(),
+            EdgeListMode::Lenient => line.split(['#', '%']).next().unwrap_or(""),
+        };
+        let tokens: Vec<&str> = content.split_whitespace().collect();
+        if tokens.is_empty() {
+            continue;
+        }
+
+        let (expected, valid_count) = match mode {
+            EdgeListMode::Strict => ("2", tokens.len() == 2),
+            EdgeListMode::Lenient => ("2 ou 3", tokens.len() == 2 || tokens.len() == 3),
+        };
+        if !valid_count {
+            return Err(error(
+                Some(line_number),
+                GraphParseErrorKind::WrongTokenCount {
+                    expected,
+                    found: tokens.len(),
+                },
+            ));
+        }
+
+        let mut vertex = |token: &str| {
+            vertex_id(token).ok_or_else(|| {
+                error(
+                    Some(line_number),
+                    GraphParseErrorKind::InvalidVertex(token.to_string()),
+                )
+            })
+        };
+        let (u, v) = (vertex(tokens[0])?, vertex(tokens[1])?);
+        if let Some(&weight) = tokens.get(2) {
+            if weight.parse::<f64>().is_err() {
+                return Err(error(
+                    Some(line_number),
+                    GraphParseErrorKind::InvalidWeight(weight.to_string()),
+                ));
+            }
+        }
+
+        add_edge(u, v);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,254 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Lines},
+};
+
+use super::{invalid_data, Graph};
+
+const GRAPH6_HEADER: &str = ">>graph6<<";
+const SPARSE6_HEADER: &str = ">>sparse6<<";
+
+// Converte os caracteres (63..=126) em valores de 6 bits
+fn decode_bytes(data: &str) -> io::Result<Vec<u8>> {
+    data.bytes()
+        .map(|b| {
+            if (63..=126).contains(&b) {
+                Ok(b - 63)
+            } else {
+                Err(invalid_data(format!(
+                    "caractere inválido em graph6/sparse6: {:?}",
+                    b as char
+                )))
+            }
+        })
+        .collect()
+}
+
+// Lê N(n) no início dos dados e devolve n e o restante
+fn decode_order(data: &[u8]) -> io::Result<(usize, &[u8])> {
+    let truncated = || invalid_data("número de vértices truncado".to_string());
+    let (len, start) = match data {
+        [63, 63, ..] => (6, 2),
+        [63, ..] => (3, 1),
+        [n, ..] => return Ok((*n as usize, &data[1..])),
+        [] => return Err(truncated()),
+    };
+    let digits = data.get(start..start + len).ok_or_else(truncated)?;
+    let n = digits
+        .iter()
+        .fold(0usize, |acc, &d| (acc << 6) | d as usize);
+    Ok((n, &data[start + len..]))
+}
+
+fn encode_order(n: usize) -> Vec<u8> {
+    let (prefix, len): (&[u8], usize) = if n <= 62 {
+        (&[], 1)
+    } else if n <= 258047 {
+        (&[63], 3)
+    } else {
+        (&[63, 63], 6)
+    };
+    let mut out = prefix.to_vec();
+    for i in (0..len).rev() {
+        out.push(((n >> (6 * i)) & 63) as u8);
+    }
+    out
+}
+
+// Agrupa bits em valores de 6 bits e converte para caracteres imprimíveis
+fn encode_bits(bits: &[bool]) -> String {
+    bits.chunks(6)
+        .map(|chunk| {
+            let value = chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |acc, (i, &bit)| acc | (u8::from(bit) << (5 - i)));
+            (value + 63) as char
+        })
+        .collect()
+}
+
+// Número de bits usado pelo sparse6 para representar um vértice
+fn sparse6_width(n: usize) -> usize {
+    let mut k = 1;
+    while (1usize << k) < n {
+        k += 1;
+    }
+    k
+}
+
+impl Graph {
+    // Decodifica um grafo em graph6 (uma linha da saída do geng)
+    pub fn from_graph6(line: &str) -> io::Result<Self> {
+        let line = line.trim();
+        let line = line.strip_prefix(GRAPH6_HEADER).unwrap_or(line);
+        let data = decode_bytes(line)?;
+        let (n, data) = decode_order(&data)?;
+
+        let num_bits = n * n.saturating_sub(1) / 2;
+        if data.len() != num_bits.div_ceil(6) {
+            return Err(invalid_data(format!(
+                "graph6 com {} vértices deveria ter {} bytes de adjacência, tem {}",
+                n,
+                num_bits.div_ceil(6),
+                data.len()
+            )));
+        }
+
+        // Triângulo superior da matriz de adjacência, coluna a coluna
+        let mut edges = vec![];
+        let mut k = 0;
+        for j in 1..n {
+            for i in 0..j {
+                if (data[k / 6] >> (5 - k % 6)) & 1 == 1 {
+                    edges.push((i, j));
+                }
+                k += 1;
+            }
+        }
+        Ok(Graph::new(n, &edges))
+    }
+
+    // Decodifica um grafo em sparse6 (linha iniciada por ':'); arestas múltiplas e laços
+    // são descartados pelo Graph e contabilizados
+    pub fn from_sparse6(line: &str) -> io::Result<Self> {
+        let line = line.trim();
+        let line = line.strip_prefix(SPARSE6_HEADER).unwrap_or(line);
+        let line = line
+            .strip_prefix(':')
+            .ok_or_else(|| invalid_data("sparse6 deve começar com ':'".to_string()))?;
+        let data = decode_bytes(line)?;
+        let (n, data) = decode_order(&data)?;
+        let k = sparse6_width(n);
+
+        let mut bits = data
+            .iter()
+            .flat_map(|&d| (0..6).rev().map(move |i| (d >> i) & 1 == 1));
+        let mut edges = vec![];
+        let mut v = 0;
+        'outer: while let Some(b) = bits.next() {
+            let mut x = 0;
+            for _ in 0..k {
+                match bits.next() {
+                    Some(bit) => x = (x << 1) | usize::from(bit),
+                    None => break 'outer,
+                }
+            }
+            if b {
+                v += 1;
+            }
+            // O preenchimento com bits 1 pode gerar valores fora do intervalo
+            if x >= n || v >= n {
+                break;
+            } else if x > v {
+                v = x;
+            } else {
+                edges.push((x, v));
+            }
+        }
+
+        Ok(Graph::new(n, &edges))
+    }
+
+    pub fn to_graph6(&self) -> String {
+        let n = self.get_num_vertices();
+        let mut adjacent = vec![false; n * n.saturating_sub(1) / 2];
+        for (u, v) in self.get_edges() {
+            adjacent[v * (v - 1) / 2 + u] = true;
+        }
+
+        let mut out: String = encode_order(n).iter().map(|&d| (d + 63) as char).collect();
+        out.push_str(&encode_bits(&adjacent));
+        out
+    }
+
+    pub fn to_sparse6(&self) -> String {
+        let n = self.get_num_vertices();
+        let k = sparse6_width(n);
+        let mut bits: Vec<bool> = vec![];
+        let push_vertex = |bits: &mut Vec<bool>, x: usize| {
+            for i in (0..k).rev() {
+                bits.push((x >> i) & 1 == 1);
+            }
+        };
+
+        let mut edges: Vec<(usize, usize)> =
+            self.get_edges().iter().map(|&(u, v)| (v, u)).collect();
+        edges.sort_unstable();
+
+        let mut current = 0;
+        for (v, u) in edges {
+            if v == current {
+                bits.push(false);
+                push_vertex(&mut bits, u);
+            } else if v == current + 1 {
+                current += 1;
+                bits.push(true);
+                push_vertex(&mut bits, u);
+            } else {
+                current = v;
+                bits.push(true);
+                push_vertex(&mut bits, v);
+                bits.push(false);
+                push_vertex(&mut bits, u);
+            }
+        }
+
+        // Caso especial do padrão: o preenchimento com 1s não pode ser lido como uma aresta
+        let padding = (6 - bits.len() % 6) % 6;
+        if k < 6 && n == (1 << k) && padding >= k && current < n - 1 {
+            bits.push(false);
+        }
+        let padding = (6 - bits.len() % 6) % 6;
+        bits.extend(std::iter::repeat_n(true, padding));
+
+        let mut out = String::from(":");
+        out.extend(encode_order(n).iter().map(|&d| (d + 63) as char));
+        out.push_str(&encode_bits(&bits));
+        out
+    }
+
+    // Abre um arquivo com um grafo graph6 ou sparse6 por linha, como a saída do geng
+    pub fn iter_graph6_file(file_path: String) -> io::Result<Graph6Reader> {
+        let file = File::open(&file_path)?;
+        Ok(Graph6Reader {
+            file_path,
+            lines: BufReader::new(file).lines(),
+            line_number: 0,
+        })
+    }
+}
+
+pub struct Graph6Reader {
+    file_path: String,
+    lines: Lines<BufReader<File>>,
+    line_number: usize,
+}
+
+impl Iterator for Graph6Reader {
+    type Item = io::Result<Graph>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            self.line_number += 1;
+
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed == GRAPH6_HEADER || trimmed == SPARSE6_HEADER {
+                continue;
+            }
+
+            let graph = if trimmed.starts_with(':') || trimmed.starts_with(SPARSE6_HEADER) {
+                Graph::from_sparse6(trimmed)
+            } else {
+                Graph::from_graph6(trimmed)
+            };
+            return Some(graph.map_err(|e| {
+                invalid_data(format!("{}:{}: {}", self.file_path, self.line_number, e))
+            }));
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+};
+
+use super::{dot::LABEL_COLORS, Graph};
+
+fn escape(name: &str) -> String {
+    name.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+impl Graph {
+    // Escreve o grafo em GraphML. Quando os rótulos de uma solução são informados, cada vértice
+    // recebe os atributos "roman" (0, 1 ou 2) e "color"
+    pub fn write_graphml(&self, file_path: String, labels: Option<&[u8]>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&file_path)?);
+
+        writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(
+            writer,
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">"
+        )?;
+        writeln!(
+            writer,
+            "  <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>"
+        )?;
+        if labels.is_some() {
+            writeln!(
+                writer,
+                "  <key id=\"roman\" for=\"node\" attr.name=\"roman\" attr.type=\"int\"/>"
+            )?;
+            writeln!(
+                writer,
+                "  <key id=\"color\" for=\"node\" attr.name=\"color\" attr.type=\"string\"/>"
+            )?;
+        }
+        writeln!(writer, "  <graph id=\"G\" edgedefault=\"undirected\">")?;
+        for v in 0..self.get_num_vertices() {
+            write!(
+                writer,
+                "    <node id=\"n{}\"><data key=\"name\">{}</data>",
+                v,
+                escape(&self.get_vertex_name(v))
+            )?;
+            if let Some(labels) = labels {
+                write!(
+                    writer,
+                    "<data key=\"roman\">{}</data><data key=\"color\">{}</data>",
+                    labels[v],
+                    LABEL_COLORS[(labels[v] as usize).min(2)]
+                )?;
+            }
+            writeln!(writer, "</node>")?;
+        }
+        for (u, v) in self.get_edges() {
+            writeln!(writer, "    <edge source=\"n{}\" target=\"n{}\"/>", u, v)?;
+        }
+        writeln!(writer, "  </graph>")?;
+        writeln!(writer, "</graphml>")?;
+        writer.flush()
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    fs::File,
+    io::{self, BufRead},
+};
+
+use super::{invalid_data, Graph};
+
+// Divide uma linha em campos de largura fixa, ignorando os campos em branco
+fn fixed_width_fields(line: &str, width: usize) -> Vec<&str> {
+    let mut fields = vec![];
+    let mut start = 0;
+    while start < line.len() {
+        let end = (start + width).min(line.len());
+        let field = line.get(start..end).unwrap_or("").trim();
+        if !field.is_empty() {
+            fields.push(field);
+        }
+        start = end;
+    }
+    fields
+}
+
+// Extrai a largura do campo de um formato Fortran de inteiros, ex: "(13I6)" -> 6
+fn integer_field_width(format: &str) -> Option<usize> {
+    let format = format.trim().trim_start_matches('(').trim_end_matches(')');
+    let (_, width) = format.split_once(['I', 'i'])?;
+    width.trim().parse::<usize>().ok().filter(|&w| w > 0)
+}
+
+fn read_integers(
+    lines: &mut impl Iterator<Item = io::Result<String>>,
+    num_lines: usize,
+    width: usize,
+    expected: usize,
+    file_path: &str,
+    what: &str,
+) -> io::Result<Vec<usize>> {
+    let mut values = Vec::with_capacity(expected);
+    for _ in 0..num_lines {
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => {
+                return Err(invalid_data(format!(
+                    "{}: arquivo terminou antes do fim dos {}",
+                    file_path, what
+                )))
+            }
+        };
+        for field in fixed_width_fields(&line, width) {
+            let value = field.parse::<usize>().map_err(|_| {
+                invalid_data(format!(
+                    "{}: valor inválido nos {}: '{}'",
+                    file_path, what, field
+                ))
+            })?;
+            values.push(value);
+        }
+    }
+    if values.len() < expected {
+        return Err(invalid_data(format!(
+            "{}: esperados {} {}, encontrados {}",
+            file_path,
+            expected,
+            what,
+            values.len()
+        )));
+    }
+    values.truncate(expected);
+    Ok(values)
+}
+
+impl Graph {
+    // Lê uma matriz no formato Harwell–Boeing (.rsa, .rua, .psa, ...) e monta o grafo
+    // não direcionado da sua estrutura; a diagonal é descartada e os valores são ignorados
+    pub fn from_harwell_boeing(file_path: String) -> io::Result<Self> {
+        let file = File::open(&file_path)?;
+        let mut lines = io::BufReader::new(file).lines();
+        let mut header_line = |n: usize| match lines.next() {
+            Some(line) => line,
+            None => Err(invalid_data(format!(
+                "{}: cabeçalho incompleto (linha {})",
+                file_path, n
+            ))),
+        };
+
+        // Linha 1: título e chave, sem informação estrutural
+        header_line(1)?;
+
+        // Linha 2: TOTCRD, PTRCRD, INDCRD, VALCRD, RHSCRD (5I14)
+        let line = header_line(2)?;
+        let cards: Vec<usize> = fixed_width_fields(&line, 14)
+            .iter()
+            .map(|s| s.parse::<usize>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| invalid_data(format!("{}: linha 2 inválida: '{}'", file_path, line)))?;
+        if cards.len() < 4 {
+            return Err(invalid_data(format!(
+                "{}: linha 2 inválida: '{}'",
+                file_path, line
+            )));
+        }
+        let (ptr_cards, ind_cards) = (cards[1], cards[2]);
+        let rhs_cards = cards.get(4).copied().unwrap_or(0);
+
+        // Linha 3: MXTYPE, NROW, NCOL, NNZERO, NELTVL (A3, 11X, 4I14)
+        let line = header_line(3)?;
+        let mxtype = line.get(0..3).unwrap_or("").to_uppercase();
+        let sizes: Vec<usize> = fixed_width_fields(line.get(14..).unwrap_or(""), 14)
+            .iter()
+            .map(|s| s.parse::<usize>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| invalid_data(format!("{}: linha 3 inválida: '{}'", file_path, line)))?;
+        if sizes.len() < 3 {
+            return Err(invalid_data(format!(
+                "{}: linha 3 inválida: '{}'",
+                file_path, line
+            )));
+        }
+        let (rows, cols, nnz) = (sizes[0], sizes[1], sizes[2]);
+
+        let mxtype: Vec<char> = mxtype.chars().collect();
+        if mxtype.len() != 3
+            || !matches!(mxtype[0], 'R' | 'C' | 'P')
+            || !matches!(mxtype[1], 'S' | 'U' | 'H' | 'Z' | 'R')
+        {
+            return Err(invalid_data(format!(
+                "{}: tipo de matriz inválido: '{}'",
+                file_path,
+                mxtype.iter().collect::<String>()
+            )));
+        }
+        if mxtype[2] != 'A' {
+            return Err(invalid_data(format!(
+                "{}: matrizes elementares (tipo '{}') não são suportadas",
+                file_path,
+                mxtype.iter().collect::<String>()
+            )));
+        }
+        if rows != cols {
+            return Err(invalid_data(format!(
+                "{}: a matriz precisa ser quadrada ({} x {})",
+                file_path, rows, cols
+            )));
+        }
+
+        // Linha 4: PTRFMT, INDFMT, VALFMT, RHSFMT (2A16, 2A20)
+        let line = header_line(4)?;
+        let ptr_format = line.get(0..16).unwrap_or("");
+        let ind_format = line.get(16..32).unwrap_or("");
+        let ptr_width = integer_field_width(ptr_format).ok_or_else(|| {
+            invalid_data(format!(
+                "{}: formato de ponteiros inválido: '{}'",
+                file_path,
+                ptr_format.trim()
+            ))
+        })?;
+        let ind_width = integer_field_width(ind_format).ok_or_else(|| {
+            invalid_data(format!(
+                "{}: formato de índices inválido: '{}'",
+                file_path,
+                ind_format.trim()
+            ))
+        })?;
+
+        // Linha 5: presente apenas quando há lados direitos
+        if rhs_cards > 0 {
+            header_line(5)?;
+        }
+
+        let pointers = read_integers(
+            &mut lines,
+            ptr_cards,
+            ptr_width,
+            cols + 1,
+            &file_path,
+            "ponteiros",
+        )?;
+        let indices = read_integers(&mut lines, ind_cards, ind_width, nnz, &file_path, "índices")?;
+
+        let mut edges: Vec<(usize, usize)> = vec![];
+        for j in 0..cols {
+            let (start, end) = (pointers[j], pointers[j + 1]);
+            if start == 0 || start > end || end > nnz + 1 {
+                return Err(invalid_data(format!(
+                    "{}: ponteiros da coluna {} inválidos ({}, {})",
+                    file_path,
+                    j + 1,
+                    start,
+                    end
+                )));
+            }
+            for &i in &indices[start - 1..end - 1] {
+                if i == 0 || i > rows {
+                    return Err(invalid_data(format!(
+                        "{}: índice de linha fora do intervalo: {}",
+                        file_path, i
+                    )));
+                }
+                edges.push((i - 1, j));
+            }
+        }
+
+        Ok(Graph::new(rows, &edges))
+    }
+}
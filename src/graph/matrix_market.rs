@@ -0,0 +1,204 @@
+use std::{
+    fs::File,
+    io::{self, BufRead},
+};
+
+use super::{invalid_data, Graph};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Layout {
+    Coordinate,
+    Array,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+    Hermitian,
+}
+
+impl Graph {
+    // Lê um arquivo Matrix Market (.mtx) e monta o grafo não direcionado da matriz:
+    // cada entrada (i, j) com i != j vira a aresta {i - 1, j - 1}, a diagonal é descartada
+    pub fn from_matrix_market(file_path: String) -> io::Result<Self> {
+        let file = File::open(&file_path)?;
+        let mut lines = io::BufReader::new(file).lines();
+
+        let header = match lines.next() {
+            Some(line) => line?,
+            None => return Err(invalid_data(format!("{}: arquivo vazio", file_path))),
+        };
+        let tokens: Vec<String> = header
+            .split_whitespace()
+            .map(|s| s.to_lowercase())
+            .collect();
+        if tokens.len() != 5 || tokens[0] != "%%matrixmarket" || tokens[1] != "matrix" {
+            return Err(invalid_data(format!(
+                "{}: cabeçalho Matrix Market inválido: '{}'",
+                file_path, header
+            )));
+        }
+
+        let layout = match tokens[2].as_str() {
+            "coordinate" => Layout::Coordinate,
+            "array" => Layout::Array,
+            other => {
+                return Err(invalid_data(format!(
+                    "{}: formato '{}' não suportado",
+                    file_path, other
+                )))
+            }
+        };
+        let field = tokens[3].as_str();
+        if !matches!(field, "real" | "integer" | "complex" | "pattern") {
+            return Err(invalid_data(format!(
+                "{}: campo '{}' não suportado",
+                file_path, field
+            )));
+        }
+        if layout == Layout::Array && field == "pattern" {
+            return Err(invalid_data(format!(
+                "{}: o formato array não admite o campo pattern",
+                file_path
+            )));
+        }
+        let symmetry = match tokens[4].as_str() {
+            "general" => Symmetry::General,
+            "symmetric" => Symmetry::Symmetric,
+            "skew-symmetric" => Symmetry::SkewSymmetric,
+            "hermitian" => Symmetry::Hermitian,
+            other => {
+                return Err(invalid_data(format!(
+                    "{}: simetria '{}' não suportada",
+                    file_path, other
+                )))
+            }
+        };
+
+        // Linhas de dados, ignorando comentários e linhas em branco
+        let mut data = lines.filter_map(|line| match line {
+            Ok(line) => {
+                let trimmed = line.trim();
+                if trimmed.is_empty() || trimmed.starts_with('%') {
+                    None
+                } else {
+                    Some(Ok(trimmed.to_string()))
+                }
+            }
+            Err(e) => Some(Err(e)),
+        });
+
+        let size_line = match data.next() {
+            Some(line) => line?,
+            None => {
+                return Err(invalid_data(format!(
+                    "{}: linha de dimensões ausente",
+                    file_path
+                )))
+            }
+        };
+        let sizes: Vec<usize> = size_line
+            .split_whitespace()
+            .map(|s| s.parse::<usize>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| {
+                invalid_data(format!(
+                    "{}: linha de dimensões inválida: '{}'",
+                    file_path, size_line
+                ))
+            })?;
+        let expected_sizes = match layout {
+            Layout::Coordinate => 3,
+            Layout::Array => 2,
+        };
+        if sizes.len() != expected_sizes {
+            return Err(invalid_data(format!(
+                "{}: linha de dimensões inválida: '{}'",
+                file_path, size_line
+            )));
+        }
+        let (rows, cols) = (sizes[0], sizes[1]);
+        if rows != cols {
+            return Err(invalid_data(format!(
+                "{}: a matriz precisa ser quadrada ({} x {})",
+                file_path, rows, cols
+            )));
+        }
+
+        let mut edges: Vec<(usize, usize)> = vec![];
+        let mut add_entry = |i: usize, j: usize| edges.push((i, j));
+
+        match layout {
+            Layout::Coordinate => {
+                let entries = sizes[2];
+                for _ in 0..entries {
+                    let line = match data.next() {
+                        Some(line) => line?,
+                        None => {
+                            return Err(invalid_data(format!(
+                                "{}: esperadas {} entradas, arquivo terminou antes",
+                                file_path, entries
+                            )))
+                        }
+                    };
+                    let mut parts = line.split_whitespace();
+                    let index = |s: Option<&str>| {
+                        s.and_then(|s| s.parse::<usize>().ok())
+                            .filter(|&k| k >= 1 && k <= rows)
+                    };
+                    match (index(parts.next()), index(parts.next())) {
+                        (Some(i), Some(j)) => add_entry(i - 1, j - 1),
+                        _ => {
+                            return Err(invalid_data(format!(
+                                "{}: entrada inválida: '{}'",
+                                file_path, line
+                            )))
+                        }
+                    }
+                }
+            }
+            Layout::Array => {
+                // Valores em ordem de coluna; matrizes simétricas trazem apenas o triângulo inferior
+                let values_per_entry = if field == "complex" { 2 } else { 1 };
+                for j in 0..cols {
+                    let first_row = match symmetry {
+                        Symmetry::General => 0,
+                        Symmetry::Symmetric | Symmetry::Hermitian => j,
+                        Symmetry::SkewSymmetric => j + 1,
+                    };
+                    for i in first_row..rows {
+                        let line = match data.next() {
+                            Some(line) => line?,
+                            None => {
+                                return Err(invalid_data(format!(
+                                    "{}: arquivo terminou antes do fim da matriz",
+                                    file_path
+                                )))
+                            }
+                        };
+                        let values: Vec<f64> = line
+                            .split_whitespace()
+                            .map(|s| s.parse::<f64>())
+                            .collect::<Result<_, _>>()
+                            .map_err(|_| {
+                                invalid_data(format!("{}: valor inválido: '{}'", file_path, line))
+                            })?;
+                        if values.len() != values_per_entry {
+                            return Err(invalid_data(format!(
+                                "{}: valor inválido: '{}'",
+                                file_path, line
+                            )));
+                        }
+                        if values.iter().any(|&x| x != 0.0) {
+                            add_entry(i, j);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Graph::new(rows, &edges))
+    }
+}
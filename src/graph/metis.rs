@@ -0,0 +1,158 @@
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{self, BufRead, BufWriter, Write},
+};
+
+use super::{invalid_data, Graph};
+
+impl Graph {
+    // Lê um grafo no formato METIS (.graph): cabeçalho "n m [fmt [ncon]]" seguido de
+    // uma linha de adjacência por vértice, numerados a partir de 1. Linhas em branco
+    // correspondem a vértices isolados, então todos os n vértices são preservados
+    pub fn from_metis(file_path: String) -> io::Result<Self> {
+        let file = File::open(&file_path)?;
+        let mut lines = io::BufReader::new(file)
+            .lines()
+            .enumerate()
+            .map(|(i, line)| line.map(|line| (i + 1, line)))
+            .filter(|line| !matches!(line, Ok((_, line)) if line.trim_start().starts_with('%')));
+
+        let (header_number, header) = loop {
+            match lines.next() {
+                Some(line) => {
+                    let (number, line) = line?;
+                    if !line.trim().is_empty() {
+                        break (number, line);
+                    }
+                }
+                None => return Err(invalid_data(format!("{}: arquivo vazio", file_path))),
+            }
+        };
+        let fields: Vec<usize> = header
+            .split_whitespace()
+            .map(|s| s.parse::<usize>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| {
+                invalid_data(format!(
+                    "{}:{}: cabeçalho inválido: '{}'",
+                    file_path, header_number, header
+                ))
+            })?;
+        if fields.len() < 2 || fields.len() > 4 {
+            return Err(invalid_data(format!(
+                "{}:{}: cabeçalho inválido: '{}'",
+                file_path, header_number, header
+            )));
+        }
+        let (num_vertices, num_edges) = (fields[0], fields[1]);
+
+        // fmt = "abc": a indica tamanhos de vértices, b pesos de vértices e c pesos de arestas
+        let fmt = header
+            .split_whitespace()
+            .nth(2)
+            .map(|s| format!("{:0>3}", s))
+            .unwrap_or_else(|| "000".to_string());
+        if fmt.len() != 3 || fmt.chars().any(|c| c != '0' && c != '1') {
+            return Err(invalid_data(format!(
+                "{}:{}: campo fmt inválido: '{}'",
+                file_path, header_number, fmt
+            )));
+        }
+        let flags: Vec<bool> = fmt.chars().map(|c| c == '1').collect();
+        let (has_sizes, has_vertex_weights, has_edge_weights) = (flags[0], flags[1], flags[2]);
+        let ncon = if has_vertex_weights {
+            fields.get(3).copied().unwrap_or(1)
+        } else {
+            0
+        };
+
+        let mut edges: HashSet<(usize, usize)> = HashSet::new();
+        for u in 0..num_vertices {
+            let (number, line) = match lines.next() {
+                Some(line) => line?,
+                None => {
+                    return Err(invalid_data(format!(
+                        "{}: esperadas {} linhas de adjacência, encontradas {}",
+                        file_path, num_vertices, u
+                    )))
+                }
+            };
+            let values: Vec<usize> = line
+                .split_whitespace()
+                .map(|s| s.parse::<usize>())
+                .collect::<Result<_, _>>()
+                .map_err(|_| {
+                    invalid_data(format!(
+                        "{}:{}: linha de adjacência inválida: '{}'",
+                        file_path, number, line
+                    ))
+                })?;
+
+            let skip = usize::from(has_sizes) + ncon;
+            let step = if has_edge_weights { 2 } else { 1 };
+            let neighbors = values.get(skip..).unwrap_or(&[]);
+            if values.len() < skip || neighbors.len() % step != 0 {
+                return Err(invalid_data(format!(
+                    "{}:{}: linha de adjacência inválida: '{}'",
+                    file_path, number, line
+                )));
+            }
+
+            for &v in neighbors.iter().step_by(step) {
+                if v == 0 || v > num_vertices {
+                    return Err(invalid_data(format!(
+                        "{}:{}: vértice fora do intervalo: {}",
+                        file_path, number, v
+                    )));
+                }
+                let v = v - 1;
+                if u != v {
+                    edges.insert((u.min(v), u.max(v)));
+                }
+            }
+        }
+
+        for line in lines {
+            let (number, line) = line?;
+            if !line.trim().is_empty() {
+                return Err(invalid_data(format!(
+                    "{}:{}: linha além dos {} vértices declarados",
+                    file_path, number, num_vertices
+                )));
+            }
+        }
+
+        if edges.len() != num_edges {
+            return Err(invalid_data(format!(
+                "{}: o cabeçalho declara {} arestas, mas foram encontradas {}",
+                file_path,
+                num_edges,
+                edges.len()
+            )));
+        }
+
+        let mut edges: Vec<(usize, usize)> = edges.into_iter().collect();
+        edges.sort_unstable();
+        Ok(Graph::new(num_vertices, &edges))
+    }
+
+    // Escreve o grafo no formato METIS, sem pesos, numerando os vértices a partir de 1
+    pub fn write_metis(&self, file_path: String) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&file_path)?);
+
+        writeln!(
+            writer,
+            "{} {}",
+            self.get_num_vertices(),
+            self.get_edges().len()
+        )?;
+        for u in 0..self.get_num_vertices() {
+            let mut neighbors: Vec<usize> = self.get_neighbors(u).clone();
+            neighbors.sort_unstable();
+            let line: Vec<String> = neighbors.iter().map(|&v| (v + 1).to_string()).collect();
+            writeln!(writer, "{}", line.join(" "))?;
+        }
+        writer.flush()
+    }
+}
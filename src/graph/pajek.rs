@@ -0,0 +1,133 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufWriter, Write},
+};
+
+use super::{invalid_data, Graph};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    None,
+    Vertices,
+    Edges,
+    EdgesList,
+}
+
+impl Graph {
+    // Lê um grafo no formato Pajek (.net). "*Vertices n" fixa a ordem do grafo; as seções
+    // "*Edges"/"*Arcs" trazem pares "u v" e "*Edgeslist"/"*Arcslist" trazem "u v1 v2 ...".
+    // Arcos são tratados como arestas e os vértices são numerados a partir de 1
+    pub fn from_pajek(file_path: String) -> io::Result<Self> {
+        let file = File::open(&file_path)?;
+        let reader = io::BufReader::new(file);
+        let mut num_vertices: Option<usize> = None;
+        let mut section = Section::None;
+        let mut edges: Vec<(usize, usize)> = vec![];
+
+        for (line_number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line_number = line_number + 1;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('%') {
+                continue;
+            }
+
+            if trimmed.starts_with('*') {
+                let mut tokens = trimmed.split_whitespace();
+                let keyword = tokens.next().unwrap_or("").to_lowercase();
+                section = match keyword.as_str() {
+                    "*vertices" => {
+                        let n = tokens.next().and_then(|s| s.parse::<usize>().ok());
+                        match n {
+                            Some(n) if num_vertices.is_none() => num_vertices = Some(n),
+                            _ => {
+                                return Err(invalid_data(format!(
+                                    "{}:{}: linha '*Vertices' inválida: '{}'",
+                                    file_path, line_number, line
+                                )))
+                            }
+                        }
+                        Section::Vertices
+                    }
+                    "*edges" | "*arcs" => Section::Edges,
+                    "*edgeslist" | "*arcslist" => Section::EdgesList,
+                    _ => {
+                        return Err(invalid_data(format!(
+                            "{}:{}: seção Pajek não suportada: '{}'",
+                            file_path, line_number, line
+                        )))
+                    }
+                };
+                continue;
+            }
+
+            let n = num_vertices.ok_or_else(|| {
+                invalid_data(format!(
+                    "{}:{}: dados antes da linha '*Vertices'",
+                    file_path, line_number
+                ))
+            })?;
+            let vertex = |s: &str| {
+                s.parse::<usize>()
+                    .ok()
+                    .filter(|&k| k >= 1 && k <= n)
+                    .map(|k| k - 1)
+                    .ok_or_else(|| {
+                        invalid_data(format!(
+                            "{}:{}: vértice inválido '{}' em '{}'",
+                            file_path, line_number, s, line
+                        ))
+                    })
+            };
+            let tokens: Vec<&str> = trimmed.split_whitespace().collect();
+
+            match section {
+                // Linhas de vértices trazem rótulos e coordenadas, apenas o índice é validado
+                Section::Vertices => {
+                    vertex(tokens[0])?;
+                }
+                Section::Edges => {
+                    if tokens.len() < 2 {
+                        return Err(invalid_data(format!(
+                            "{}:{}: aresta inválida: '{}'",
+                            file_path, line_number, line
+                        )));
+                    }
+                    // Um terceiro campo, quando presente, é o peso da aresta
+                    edges.push((vertex(tokens[0])?, vertex(tokens[1])?));
+                }
+                Section::EdgesList => {
+                    let u = vertex(tokens[0])?;
+                    for token in &tokens[1..] {
+                        edges.push((u, vertex(token)?));
+                    }
+                }
+                Section::None => {
+                    return Err(invalid_data(format!(
+                        "{}:{}: dados fora de uma seção: '{}'",
+                        file_path, line_number, line
+                    )))
+                }
+            }
+        }
+
+        let num_vertices = num_vertices
+            .ok_or_else(|| invalid_data(format!("{}: linha '*Vertices n' ausente", file_path)))?;
+        Ok(Graph::new(num_vertices, &edges))
+    }
+
+    // Escreve o grafo no formato Pajek; o vértice v vira o vértice v + 1 com rótulo "v"
+    pub fn write_pajek(&self, file_path: String) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&file_path)?);
+
+        writeln!(writer, "*Vertices {}", self.get_num_vertices())?;
+        for v in 0..self.get_num_vertices() {
+            writeln!(writer, "{} \"{}\"", v + 1, v)?;
+        }
+        writeln!(writer, "*Edges")?;
+        for (u, v) in self.get_edges() {
+            writeln!(writer, "{} {}", u + 1, v + 1)?;
+        }
+        writer.flush()
+    }
+}
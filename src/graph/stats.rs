@@ -0,0 +1,165 @@
+use super::Graph;
+
+// Características estruturais de uma instância, usadas para explicar a variação de
+// qualidade do AG entre grafos
+#[derive(Clone, Debug)]
+pub struct GraphStats {
+    pub num_components: usize,
+    // Tamanhos das componentes conexas, em ordem decrescente
+    pub component_sizes: Vec<usize>,
+    pub min_degree: usize,
+    pub max_degree: usize,
+    pub mean_degree: f64,
+    // degree_histogram[d] é o número de vértices de grau d
+    pub degree_histogram: Vec<usize>,
+    pub density: f64,
+    pub isolated_vertices: usize,
+    pub leaves: usize,
+    pub is_bipartite: bool,
+    // Limite inferior do diâmetro (maior excentricidade encontrada por varredura dupla de BFS
+    // em cada componente)
+    pub approx_diameter: usize,
+}
+
+impl GraphStats {
+    pub fn csv_header() -> &'static str {
+        "num_components,largest_component,min_degree,max_degree,mean_degree,density,isolated_vertices,leaves,bipartite,approx_diameter"
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{:.4},{:.6},{},{},{},{}",
+            self.num_components,
+            self.component_sizes.first().copied().unwrap_or(0),
+            self.min_degree,
+            self.max_degree,
+            self.mean_degree,
+            self.density,
+            self.isolated_vertices,
+            self.leaves,
+            self.is_bipartite,
+            self.approx_diameter
+        )
+    }
+}
+
+// BFS a partir de source; devolve o vértice alcançado mais distante e sua distância.
+// O vetor de distâncias é compartilhado entre chamadas e restaurado ao final
+fn farthest_vertex(graph: &Graph, source: usize, distance: &mut [usize]) -> (usize, usize) {
+    let mut visited = vec![source];
+    distance[source] = 0;
+    let mut i = 0;
+    while i < visited.len() {
+        let u = visited[i];
+        for &v in graph.get_neighbors(u) {
+            if distance[v] == usize::MAX {
+                distance[v] = distance[u] + 1;
+                visited.push(v);
+            }
+        }
+        i += 1;
+    }
+
+    // Em BFS o último vértice visitado é o mais distante
+    let last = *visited.last().unwrap();
+    let result = (last, distance[last]);
+    for v in visited {
+        distance[v] = usize::MAX;
+    }
+    result
+}
+
+impl Graph {
+    // Componentes conexas, cada uma como a lista de seus vértices
+    pub fn get_connected_components(&self) -> Vec<Vec<usize>> {
+        let n = self.get_num_vertices();
+        let mut visited = vec![false; n];
+        let mut components = vec![];
+
+        for start in 0..n {
+            if visited[start] {
+                continue;
+            }
+            let mut component = vec![start];
+            visited[start] = true;
+            let mut i = 0;
+            while i < component.len() {
+                let u = component[i];
+                for &v in self.get_neighbors(u) {
+                    if !visited[v] {
+                        visited[v] = true;
+                        component.push(v);
+                    }
+                }
+                i += 1;
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    pub fn stats(&self) -> GraphStats {
+        let n = self.get_num_vertices();
+        let m = self.get_graph_size();
+        let degrees: Vec<usize> = (0..n).map(|v| self.get_vertex_degree(v)).collect();
+        let max_degree = degrees.iter().copied().max().unwrap_or(0);
+
+        let mut degree_histogram = vec![0; max_degree + 1];
+        for &d in &degrees {
+            degree_histogram[d] += 1;
+        }
+
+        // Bipartição por 2-coloração via BFS
+        let mut color: Vec<Option<bool>> = vec![None; n];
+        let mut is_bipartite = true;
+        let components = self.get_connected_components();
+        for component in &components {
+            color[component[0]] = Some(false);
+            for &u in component {
+                let cu = color[u].unwrap_or(false);
+                for &v in self.get_neighbors(u) {
+                    match color[v] {
+                        None => color[v] = Some(!cu),
+                        Some(cv) if cv == cu => is_bipartite = false,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        let mut distance = vec![usize::MAX; n];
+        let approx_diameter = components
+            .iter()
+            .map(|component| {
+                let (a, _) = farthest_vertex(self, component[0], &mut distance);
+                farthest_vertex(self, a, &mut distance).1
+            })
+            .max()
+            .unwrap_or(0);
+
+        let mut component_sizes: Vec<usize> = components.iter().map(|c| c.len()).collect();
+        component_sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        GraphStats {
+            num_components: components.len(),
+            component_sizes,
+            min_degree: degrees.iter().copied().min().unwrap_or(0),
+            max_degree,
+            mean_degree: if n > 0 {
+                2.0 * m as f64 / n as f64
+            } else {
+                0.0
+            },
+            degree_histogram,
+            density: if n > 1 {
+                2.0 * m as f64 / (n as f64 * (n - 1) as f64)
+            } else {
+                0.0
+            },
+            isolated_vertices: degrees.iter().filter(|&&d| d == 0).count(),
+            leaves: degrees.iter().filter(|&&d| d == 1).count(),
+            is_bipartite,
+            approx_diameter,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufWriter, Write},
+};
+
+use crate::genetic_algorithm::Solution;
+use crate::graph::Graph;
+use crate::verify::verify;
+
+// Formulação 0/1 padrão da dominação romana: x_v = 1 quando v recebe 1 e y_v = 1 quando v
+// recebe 2. Minimiza Σ x_v + 2 y_v sujeito a x_v + y_v + Σ_{u ∈ N(v)} y_u >= 1 (restrição c_v)
+
+// Termos por linha no formato LP, que limita o comprimento das linhas
+const LP_TERMS_PER_LINE: usize = 10;
+
+fn write_lp_terms<W: Write>(writer: &mut W, terms: &[String]) -> io::Result<()> {
+    for (i, term) in terms.iter().enumerate() {
+        if i > 0 {
+            if i % LP_TERMS_PER_LINE == 0 {
+                write!(writer, "\n   ")?;
+            }
+            write!(writer, " +")?;
+        }
+        write!(writer, " {}", term)?;
+    }
+    Ok(())
+}
+
+// Escreve o modelo no formato CPLEX LP
+pub fn write_lp(graph: &Graph, file_path: String) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(&file_path)?);
+    let n = graph.get_num_vertices();
+
+    writeln!(
+        writer,
+        "\\ Dominação romana: {} vértices, {} arestas",
+        n,
+        graph.get_graph_size()
+    )?;
+    writeln!(writer, "Minimize")?;
+    write!(writer, " obj:")?;
+    let objective: Vec<String> = (0..n)
+        .flat_map(|v| [format!("x_{}", v), format!("2 y_{}", v)])
+        .collect();
+    write_lp_terms(&mut writer, &objective)?;
+    writeln!(writer)?;
+
+    writeln!(writer, "Subject To")?;
+    for v in 0..n {
+        write!(writer, " c_{}:", v)?;
+        let mut terms = vec![format!("x_{}", v), format!("y_{}", v)];
+        terms.extend(graph.get_neighbors(v).iter().map(|u| format!("y_{}", u)));
+        write_lp_terms(&mut writer, &terms)?;
+        writeln!(writer, " >= 1")?;
+    }
+
+    writeln!(writer, "Binary")?;
+    for v in 0..n {
+        writeln!(writer, " x_{} y_{}", v, v)?;
+    }
+    writeln!(writer, "End")?;
+    writer.flush()
+}
+
+// Escreve o modelo no formato MPS com colunas fixas, aceito também pelos leitores de MPS livre
+pub fn write_mps(graph: &Graph, file_path: String) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(&file_path)?);
+    let n = graph.get_num_vertices();
+    let entry =
+        |column: &str, row: &str, value: usize| format!("    {:<8}  {:<8}  {}", column, row, value);
+
+    writeln!(writer, "NAME          ROMAN")?;
+    writeln!(writer, "ROWS")?;
+    writeln!(writer, " N  obj")?;
+    for v in 0..n {
+        writeln!(writer, " G  c_{}", v)?;
+    }
+
+    writeln!(writer, "COLUMNS")?;
+    writeln!(
+        writer,
+        "    MARKER                 'MARKER'                 'INTORG'"
+    )?;
+    for v in 0..n {
+        let (x, y, row) = (format!("x_{}", v), format!("y_{}", v), format!("c_{}", v));
+        writeln!(writer, "{}", entry(&x, "obj", 1))?;
+        writeln!(writer, "{}", entry(&x, &row, 1))?;
+        writeln!(writer, "{}", entry(&y, "obj", 2))?;
+        writeln!(writer, "{}", entry(&y, &row, 1))?;
+        for &u in graph.get_neighbors(v) {
+            writeln!(writer, "{}", entry(&y, &format!("c_{}", u), 1))?;
+        }
+    }
+    writeln!(
+        writer,
+        "    MARKER                 'MARKER'                 'INTEND'"
+    )?;
+
+    writeln!(writer, "RHS")?;
+    for v in 0..n {
+        writeln!(writer, "{}", entry("rhs", &format!("c_{}", v), 1))?;
+    }
+
+    writeln!(writer, "BOUNDS")?;
+    for v in 0..n {
+        writeln!(writer, " UP bnd       {:<8}  1", format!("x_{}", v))?;
+        writeln!(writer, " UP bnd       {:<8}  1", format!("y_{}", v))?;
+    }
+    writeln!(writer, "ENDATA")?;
+    writer.flush()
+}
+
+// Lê o arquivo de solução de um solver e o converte numa rotulação do grafo. Cada linha é
+// procurada por um nome de variável (x_v ou y_v) seguido do seu valor, o que cobre os
+// formatos de HiGHS ("x_0 1"), CBC ("0 x_0 1 0"), SCIP e Gurobi; variáveis ausentes valem 0.
+// A rotulação é verificada e soluções inviáveis geram erro
+pub fn read_solution(graph: &Graph, file_path: String) -> io::Result<Solution> {
+    let file = File::open(&file_path)?;
+    let reader = io::BufReader::new(file);
+    let n = graph.get_num_vertices();
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+    let mut labels = vec![0; n];
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+        // O HiGHS escreve os valores duais depois dos primais, com os mesmos nomes de colunas
+        if line.starts_with("# Dual") {
+            break;
+        }
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        for pair in tokens.windows(2) {
+            let Some((kind, vertex)) = pair[0].split_once('_') else {
+                continue;
+            };
+            let label = match kind {
+                "x" => 1,
+                "y" => 2,
+                _ => continue,
+            };
+            let Ok(vertex) = vertex.parse::<usize>() else {
+                continue;
+            };
+            let Ok(value) = pair[1].parse::<f64>() else {
+                continue;
+            };
+            if vertex >= n {
+                return Err(invalid(format!(
+                    "{}:{}: vértice inválido {} em '{}'",
+                    file_path,
+                    line_number + 1,
+                    vertex,
+                    line
+                )));
+            }
+            if value > 0.5 {
+                labels[vertex] = labels[vertex].max(label);
+            }
+        }
+    }
+
+    let verification = verify(&graph.freeze(), &labels)?;
+    if let Some(&v) = verification.undominated.first() {
+        return Err(invalid(format!(
+            "{}: a solução não é uma função de dominação romana: o vértice {} tem rótulo 0 e nenhum vizinho com rótulo 2",
+            file_path,
+            graph.get_vertex_name(v)
+        )));
+    }
+    Ok(Solution::from_labels(labels))
+}
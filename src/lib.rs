@@ -0,0 +1,9 @@
+pub mod bounds;
+pub mod exact;
+pub mod generators;
+pub mod genetic_algorithm;
+pub mod graph;
+pub mod ilp;
+pub mod maxsat;
+pub mod reduction;
+pub mod verify;
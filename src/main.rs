@@ -1,19 +1,215 @@
-use genetic_algorithm::RomanDominationGA;
-use graph::Graph;
-
-mod genetic_algorithm;
-mod graph;
+use roman_domination_ga::bounds::Bounds;
+use roman_domination_ga::exact;
+use roman_domination_ga::genetic_algorithm::{
+    Crossover, Mutation, Repair, RomanDominationGA, Solution,
+};
+use roman_domination_ga::graph::{EdgeListMode, Graph, GraphStats};
+use roman_domination_ga::ilp;
+use roman_domination_ga::maxsat;
+use roman_domination_ga::reduction;
+use roman_domination_ga::verify;
 
 use std::env;
+use std::io;
 use std::path::Path;
 use std::process::exit;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+// Remove a flag da lista de argumentos, devolvendo se ela estava presente
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let len = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != len
+}
+
+// Remove a opção e seu valor da lista de argumentos
+fn take_option(args: &mut Vec<String>, option: &str) -> Option<String> {
+    let pos = args.iter().position(|arg| arg == option)?;
+    if pos + 1 >= args.len() {
+        eprintln!("Erro: a opção '{}' exige um valor.", option);
+        exit(1);
+    }
+    let value = args.remove(pos + 1);
+    args.remove(pos);
+    Some(value)
+}
+
+// Quando o arquivo de entrada tem vários grafos, cada um ganha seu próprio arquivo de saída
+fn output_path(path: &str, graph_name: &str, multiple: bool) -> String {
+    if !multiple {
+        return path.to_string();
+    }
+    let path = Path::new(path);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("saida");
+    let file_name = match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) => format!("{}_{}.{}", stem, graph_name, ext),
+        None => format!("{}_{}", stem, graph_name),
+    };
+    path.with_file_name(file_name)
+        .to_string_lossy()
+        .into_owned()
+}
+
+// Lê o arquivo de entrada escolhendo o formato pela extensão; listas de arestas são lidas no
+// modo informado
+fn load_graphs(
+    file_path: &str,
+    compact_ids: bool,
+    mode: EdgeListMode,
+) -> io::Result<Vec<(String, Graph)>> {
+    let file_name = Path::new(file_path)
+        .file_stem()
+        .and_then(|name| name.to_str())
+        .unwrap_or("arquivo");
+
+    // Arquivos graph6/sparse6 podem conter vários grafos, um por linha
+    let graphs = match Path::new(file_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+    {
+        Some("g6" | "s6") => Graph::iter_graph6_file(file_path.to_string())?
+            .enumerate()
+            .map(|(i, graph)| graph.map(|graph| (format!("{}_{}", file_name, i + 1), graph)))
+            .collect::<Result<_, _>>()?,
+        extension => {
+            let graph = match extension {
+                Some("mtx") => Graph::from_matrix_market(file_path.to_string())?,
+                Some("col" | "clq" | "dimacs") => Graph::from_dimacs(file_path.to_string())?,
+                Some("graph" | "metis") => Graph::from_metis(file_path.to_string())?,
+                Some("net") => Graph::from_pajek(file_path.to_string())?,
+                Some("hb" | "rsa" | "rua" | "rza" | "rra" | "psa" | "pua" | "pza" | "pra") => {
+                    Graph::from_harwell_boeing(file_path.to_string())?
+                }
+                _ if compact_ids => Graph::from_edge_list_compact(file_path.to_string(), mode)?,
+                _ => Graph::from_edge_list(file_path.to_string(), mode)?,
+            };
+            vec![(file_name.to_string(), graph)]
+        }
+    };
+    Ok(graphs)
+}
+
+// Comando verify: confere se a rotulação de um arquivo é uma função de dominação romana do grafo
+fn run_verify(
+    graph_path: &str,
+    labels_path: &str,
+    compact_ids: bool,
+    mode: EdgeListMode,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut graphs = load_graphs(graph_path, compact_ids, mode).unwrap_or_else(|e| {
+        eprintln!("Erro: {}", e);
+        exit(1);
+    });
+    if graphs.len() != 1 {
+        eprintln!("Erro: o arquivo do grafo deve conter um único grafo.");
+        exit(1);
+    }
+    let (_, graph) = graphs.remove(0);
+    let verification = verify::verify_file(&graph, labels_path.to_string())?;
+
+    for &v in &verification.undominated {
+        println!(
+            "Vértice {} tem rótulo 0 e nenhum vizinho com rótulo 2.",
+            graph.get_vertex_name(v)
+        );
+    }
+    println!("peso = {}", verification.weight);
+    println!(
+        "|V0| = {}, |V1| = {}, |V2| = {}",
+        verification.label_counts[0], verification.label_counts[1], verification.label_counts[2]
+    );
+    if !verification.is_valid() {
+        println!(
+            "Rotulação inválida: {} vértices sem dominação.",
+            verification.undominated.len()
+        );
+        exit(1);
+    }
+    println!("Rotulação válida.");
+    Ok(())
+}
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+
+    // Opções
+    let compact_ids = take_flag(&mut args, "--compact-ids");
+    let edge_list_mode = if take_flag(&mut args, "--strict") {
+        EdgeListMode::Strict
+    } else {
+        EdgeListMode::Lenient
+    };
+    let print_stats = take_flag(&mut args, "--stats");
+    let reduce = take_flag(&mut args, "--reduce");
+    let dot_path = take_option(&mut args, "--dot");
+    let graphml_path = take_option(&mut args, "--graphml");
+    let lp_path = take_option(&mut args, "--lp");
+    let mps_path = take_option(&mut args, "--mps");
+    let ilp_solution_path = take_option(&mut args, "--ilp-solution");
+    let wcnf_path = take_option(&mut args, "--wcnf");
+    let maxsat_model_path = take_option(&mut args, "--maxsat-model");
+    let labels_path = take_option(&mut args, "--labels");
+    let trial_labels_path = take_option(&mut args, "--trial-labels");
+    let mutation_rate: Option<f32> = take_option(&mut args, "--mutation-rate").map(|value| {
+        value
+            .parse()
+            .ok()
+            .filter(|rate| (0.0..=1.0).contains(rate))
+            .unwrap_or_else(|| {
+                eprintln!("Erro: '--mutation-rate' deve ser um número entre 0 e 1.");
+                exit(1);
+            })
+    });
+    let mutations: Option<Vec<Mutation>> = take_option(&mut args, "--mutations").map(|value| {
+        value
+            .split(',')
+            .map(|name| name.trim().parse())
+            .collect::<Result<_, _>>()
+            .unwrap_or_else(|err| {
+                eprintln!("Erro: {}.", err);
+                exit(1);
+            })
+    });
+    let crossover: Option<Crossover> = take_option(&mut args, "--crossover").map(|value| {
+        value.parse().unwrap_or_else(|err| {
+            eprintln!("Erro: {}.", err);
+            exit(1);
+        })
+    });
+    let two_children = take_flag(&mut args, "--two-children");
+    let local_search = take_flag(&mut args, "--local-search");
+    let no_decompose = take_flag(&mut args, "--no-decompose");
+    let repair: Option<Repair> = take_option(&mut args, "--repair").map(|value| {
+        value.parse().unwrap_or_else(|err| {
+            eprintln!("Erro: {}.", err);
+            exit(1);
+        })
+    });
+    let run_exact = take_flag(&mut args, "--exact");
+    let node_limit: Option<usize> = take_option(&mut args, "--node-limit").map(|value| {
+        value.parse().unwrap_or_else(|_| {
+            eprintln!("Erro: '--node-limit' deve ser um número inteiro positivo.");
+            exit(1);
+        })
+    });
+    let time_limit: Option<Duration> = take_option(&mut args, "--time-limit").map(|value| {
+        value
+            .parse()
+            .ok()
+            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+            .unwrap_or_else(|| {
+                eprintln!("Erro: '--time-limit' deve ser um número de segundos.");
+                exit(1);
+            })
+    });
+
+    if args.len() == 4 && args[1] == "verify" {
+        return run_verify(&args[2], &args[3], compact_ids, edge_list_mode);
+    }
+
     if args.len() < 3 {
         eprintln!(
-            "Uso: {} <file_path> <trials> [max_stagnant] [generations] [tournament_size] [crossover_prob] [pop_size]",
+            "Uso: {0} <file_path> <trials> [max_stagnant] [generations] [tournament_size] [crossover_prob] [pop_size] [--compact-ids] [--strict] [--stats] [--reduce] [--dot <arquivo>] [--graphml <arquivo>] [--lp <arquivo>] [--mps <arquivo>] [--ilp-solution <arquivo>] [--wcnf <arquivo>] [--maxsat-model <arquivo>] [--labels <arquivo>] [--trial-labels <arquivo>] [--mutation-rate <taxa>] [--mutations <lista>] [--crossover <operador>] [--two-children] [--repair <estratégia>] [--local-search] [--no-decompose] [--exact] [--node-limit <nós>] [--time-limit <segundos>]\n     {0} verify <file_path> <arquivo_rotulação> [--compact-ids] [--strict]",
             args[0]
         );
         exit(1);
@@ -34,6 +230,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     const DEFAULT_GENERATIONS: usize = 1000;
     const DEFAULT_TOURNAMENT_SIZE: usize = 2;
     const DEFAULT_CROSSOVER_PROBABILITY: f32 = 0.9;
+    const DEFAULT_MUTATION_RATE: f32 = 0.0;
+    let mutation_rate = mutation_rate.unwrap_or(DEFAULT_MUTATION_RATE);
 
     // Parâmetros opcionais
     let max_stagnant = if args.len() > 3 {
@@ -75,7 +273,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let crossover_probability = if args.len() > 6 {
         match args[6].parse() {
             Ok(n) => {
-                if n < 0.0 || n > 1.0 {
+                if !(0.0..=1.0).contains(&n) {
                     eprintln!("Erro: 'crossover_probability' deve estar entre 0 e 1.");
                     exit(1);
                 }
@@ -90,51 +288,228 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         DEFAULT_CROSSOVER_PROBABILITY
     };
 
-    let file_name = Path::new(&file_path)
-        .file_stem()
-        .and_then(|name| name.to_str())
-        .unwrap_or("arquivo");
-
-    let graph = Graph::from_file(file_path.to_string())?;
-    let graph_order = graph.get_num_vertices();
-    let graph_size = graph.get_graph_size();
+    let graphs = load_graphs(&file_path, compact_ids, edge_list_mode).unwrap_or_else(|e| {
+        eprintln!("Erro: {}", e);
+        exit(1);
+    });
 
     // pop_size agora pode ser passado como parâmetro opcional
-    let pop_size = if args.len() > 7 {
+    let pop_size_arg: Option<usize> = if args.len() > 7 {
         match args[7].parse() {
-            Ok(n) => n,
+            Ok(n) => Some(n),
             Err(_) => {
                 eprintln!("Erro: 'pop_size' deve ser um número inteiro positivo.");
                 exit(1);
             }
         }
     } else {
-        (graph.get_num_vertices() as f64 / 1.5).round() as usize
+        None
     };
 
-    println!("graph_name,graph_order,graph_size,fitness_value,elapsed_time(microsecond)");
-    let mut rdga = RomanDominationGA::new(graph, Some(pop_size));
-
-    for _ in 0..trials {
-        let start = Instant::now();
-        let solution = rdga.run(
-            generations,
-            max_stagnant,
-            tournament_size,
-            crossover_probability,
+    if print_stats {
+        println!(
+            "graph_name,graph_order,graph_size,{},fitness_value,lower_bound,upper_bound,gap,elapsed_time(microsecond)",
+            GraphStats::csv_header()
         );
-        let end = Instant::now();
-        let elapsed_time = end.duration_since(start);
-        let elapsed_in_microseconds = elapsed_time.as_micros();
+    } else {
         println!(
-            "{},{},{},{},{}",
-            file_name,
-            graph_order,
-            graph_size,
-            solution.fitness.unwrap_or_default(),
-            elapsed_in_microseconds
+            "graph_name,graph_order,graph_size,fitness_value,lower_bound,upper_bound,gap,elapsed_time(microsecond)"
         );
     }
+    let multiple = graphs.len() > 1;
+    for (graph_name, graph) in graphs {
+        if graph.get_dropped_duplicates() > 0 || graph.get_dropped_self_loops() > 0 {
+            eprintln!(
+                "Aviso: {}: {} arestas repetidas e {} laços descartados.",
+                graph_name,
+                graph.get_dropped_duplicates(),
+                graph.get_dropped_self_loops()
+            );
+        }
+        let graph_order = graph.get_num_vertices();
+        let graph_size = graph.get_graph_size();
+        // Colunas de estatísticas do grafo, logo após graph_order e graph_size
+        let graph_columns = if print_stats {
+            format!("{},{},{}", graph_order, graph_size, graph.stats().to_csv())
+        } else {
+            format!("{},{}", graph_order, graph_size)
+        };
+        // Modelos ILP e MaxSAT do grafo original, para resolver com um solver externo
+        if let Some(path) = &lp_path {
+            ilp::write_lp(&graph, output_path(path, &graph_name, multiple))?;
+        }
+        if let Some(path) = &mps_path {
+            ilp::write_mps(&graph, output_path(path, &graph_name, multiple))?;
+        }
+        if let Some(path) = &wcnf_path {
+            maxsat::write_wcnf(&graph, output_path(path, &graph_name, multiple))?;
+        }
+        // Soluções de solvers externos, verificadas e comparadas com o AG após as execuções
+        let mut external_solutions = vec![];
+        if let Some(path) = &ilp_solution_path {
+            let path = output_path(path, &graph_name, multiple);
+            external_solutions.push(("ILP", ilp::read_solution(&graph, path)?));
+        }
+        if let Some(path) = &maxsat_model_path {
+            let path = output_path(path, &graph_name, multiple);
+            external_solutions.push(("MaxSAT", maxsat::read_model(&graph, path)?));
+        }
+
+        // Limites calculados no grafo original, antes de uma eventual redução
+        let bounds = Bounds::new(&graph);
+        let original_graph =
+            if dot_path.is_some() || graphml_path.is_some() || labels_path.is_some() || run_exact {
+                Some(graph.clone())
+            } else {
+                None
+            };
+
+        // Criado antes da redução, com os nomes dos vértices do grafo original
+        let mut trial_labels = match &trial_labels_path {
+            Some(path) => Some(verify::TrialLabelsWriter::create(
+                &graph,
+                output_path(path, &graph_name, multiple),
+            )?),
+            None => None,
+        };
+
+        // Com --reduce o AG resolve o grafo reduzido e as soluções são levadas de volta ao
+        // grafo original
+        let (graph, kernel) = if reduce {
+            let kernel = reduction::reduce(&graph);
+            eprintln!(
+                "Redução: {}: {} -> {} vértices, {} -> {} arestas.",
+                graph_name,
+                graph_order,
+                kernel.get_graph().get_num_vertices(),
+                graph_size,
+                kernel.get_graph().get_graph_size()
+            );
+            (kernel.get_graph().clone(), Some(kernel))
+        } else {
+            (graph, None)
+        };
+        let pop_size = pop_size_arg
+            .unwrap_or_else(|| (graph.get_num_vertices() as f64 / 1.5).round() as usize);
+        let mut rdga = RomanDominationGA::new(graph, Some(pop_size));
+        if let Some(mutations) = &mutations {
+            rdga.set_mutations(mutations);
+        }
+        if let Some(repair) = repair {
+            rdga.set_repair(repair);
+        }
+        if local_search {
+            rdga.set_local_search(true);
+        }
+        if no_decompose {
+            rdga.set_decompose(false);
+        }
+        if crossover.is_some() || two_children {
+            rdga.set_crossover(crossover.unwrap_or(Crossover::KPoint(2)), two_children);
+        }
+        let mut best_solution = None;
+
+        for trial in 0..trials {
+            let start = Instant::now();
+            let solution = rdga.run(
+                generations,
+                max_stagnant,
+                tournament_size,
+                crossover_probability,
+                mutation_rate,
+            );
+            let solution = match &kernel {
+                Some(kernel) => Solution::from_labels(kernel.lift(solution.get_labels())),
+                None => solution,
+            };
+            let end = Instant::now();
+            let elapsed_time = end.duration_since(start);
+            let elapsed_in_microseconds = elapsed_time.as_micros();
+            let fitness = solution.fitness.unwrap_or_default();
+            println!(
+                "{},{},{},{},{},{},{}",
+                graph_name,
+                graph_columns,
+                fitness,
+                bounds.lower,
+                bounds.upper,
+                bounds.gap(fitness),
+                elapsed_in_microseconds
+            );
+
+            if let Some(writer) = &mut trial_labels {
+                writer.write(trial + 1, solution.get_labels())?;
+            }
+            if best_solution.as_ref().is_none_or(|best| solution < *best) {
+                best_solution = Some(solution);
+            }
+        }
+        if let Some(writer) = trial_labels {
+            writer.finish()?;
+        }
+
+        for (solver, solution) in external_solutions {
+            let fitness = solution.fitness.unwrap_or_default();
+            eprintln!(
+                "Solução {}: {}: peso = {} (viável), melhor do AG = {}.",
+                solver,
+                graph_name,
+                fitness,
+                best_solution
+                    .as_ref()
+                    .and_then(|s| s.fitness)
+                    .unwrap_or_default()
+            );
+            if best_solution.as_ref().is_none_or(|best| solution < *best) {
+                best_solution = Some(solution);
+            }
+        }
+
+        // Com --exact, a melhor solução do AG serve de incumbente para o branch-and-bound
+        if let (true, Some(graph)) = (run_exact, &original_graph) {
+            let incumbent = best_solution.as_ref().map(|s| s.get_labels());
+            let result = exact::solve(graph, incumbent, node_limit, time_limit);
+            let fitness = result.solution.fitness.unwrap_or_default();
+            if result.optimal {
+                eprintln!(
+                    "Exato: {}: ótimo = {} ({} nós).",
+                    graph_name, fitness, result.nodes
+                );
+            } else {
+                eprintln!(
+                    "Exato: {}: limite atingido, melhor = {}, limite inferior = {}, gap = {} ({} nós).",
+                    graph_name,
+                    fitness,
+                    result.lower_bound,
+                    result.gap(),
+                    result.nodes
+                );
+            }
+            if best_solution
+                .as_ref()
+                .is_none_or(|best| result.solution < *best)
+            {
+                best_solution = Some(result.solution);
+            }
+        }
+
+        // Exporta o grafo com a melhor rotulação encontrada
+        if let (true, Some(graph)) = (
+            dot_path.is_some() || graphml_path.is_some() || labels_path.is_some(),
+            &original_graph,
+        ) {
+            let labels = best_solution.as_ref().map(|s| s.get_labels());
+            if let (Some(path), Some(labels)) = (&labels_path, labels) {
+                verify::write_labels(graph, labels, output_path(path, &graph_name, multiple))?;
+            }
+            if let Some(path) = &dot_path {
+                graph.write_dot(output_path(path, &graph_name, multiple), labels)?;
+            }
+            if let Some(path) = &graphml_path {
+                graph.write_graphml(output_path(path, &graph_name, multiple), labels)?;
+            }
+        }
+    }
 
     Ok(())
 }
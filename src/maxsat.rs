@@ -0,0 +1,130 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufWriter, Write},
+};
+
+use crate::genetic_algorithm::Solution;
+use crate::graph::Graph;
+use crate::verify::verify;
+
+// Codificação MaxSAT parcial com pesos: a variável 2v + 1 indica rótulo 1 em v e a variável
+// 2v + 2 indica rótulo 2. As cláusulas rígidas exigem que cada vértice tenha rótulo positivo
+// ou um vizinho fechado com rótulo 2; as cláusulas flexíveis (¬x_v, peso 1) e (¬y_v, peso 2)
+// fazem o custo da atribuição ser o peso da rotulação
+
+fn label_variable(vertex: usize, label: u8) -> usize {
+    2 * vertex + label as usize
+}
+
+// Escreve a fórmula no formato WCNF clássico ("p wcnf"), em que as cláusulas rígidas têm o
+// peso top, maior que a soma dos pesos flexíveis
+pub fn write_wcnf(graph: &Graph, file_path: String) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(&file_path)?);
+    let n = graph.get_num_vertices();
+    let top = 3 * n + 1;
+
+    writeln!(
+        writer,
+        "c Dominação romana: {} vértices, {} arestas",
+        n,
+        graph.get_graph_size()
+    )?;
+    writeln!(writer, "p wcnf {} {} {}", 2 * n, 3 * n, top)?;
+    for v in 0..n {
+        write!(
+            writer,
+            "{} {} {}",
+            top,
+            label_variable(v, 1),
+            label_variable(v, 2)
+        )?;
+        for &u in graph.get_neighbors(v) {
+            write!(writer, " {}", label_variable(u, 2))?;
+        }
+        writeln!(writer, " 0")?;
+    }
+    for v in 0..n {
+        writeln!(writer, "1 -{} 0", label_variable(v, 1))?;
+        writeln!(writer, "2 -{} 0", label_variable(v, 2))?;
+    }
+    writer.flush()
+}
+
+// Lê o modelo devolvido pelo solver. As linhas "v" podem listar literais ("v 1 -2 3 ... 0")
+// ou, no formato das MaxSAT Evaluations recentes, uma sequência de 0 e 1 ("v 0110...");
+// as demais linhas são ignoradas. Com as duas variáveis verdadeiras o vértice recebe 2
+pub fn read_model(graph: &Graph, file_path: String) -> io::Result<Solution> {
+    let file = File::open(&file_path)?;
+    let reader = io::BufReader::new(file);
+    let n = graph.get_num_vertices();
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+    let mut assignment = vec![false; 2 * n + 1];
+    let mut found = false;
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+        let Some(values) = line.strip_prefix("v ") else {
+            continue;
+        };
+        found = true;
+        let values = values.trim();
+
+        if values.len() == 2 * n && values.bytes().all(|b| b == b'0' || b == b'1') {
+            for (i, b) in values.bytes().enumerate() {
+                assignment[i + 1] = b == b'1';
+            }
+            continue;
+        }
+        for token in values.split_whitespace() {
+            let literal: i64 = token.parse().map_err(|_| {
+                invalid(format!(
+                    "{}:{}: literal inválido '{}'",
+                    file_path,
+                    line_number + 1,
+                    token
+                ))
+            })?;
+            let variable = literal.unsigned_abs() as usize;
+            if variable > 2 * n {
+                return Err(invalid(format!(
+                    "{}:{}: variável {} fora da codificação de {} vértices",
+                    file_path,
+                    line_number + 1,
+                    variable,
+                    n
+                )));
+            }
+            if variable > 0 {
+                assignment[variable] = literal > 0;
+            }
+        }
+    }
+
+    if !found {
+        return Err(invalid(format!(
+            "{}: nenhuma linha 'v' no modelo",
+            file_path
+        )));
+    }
+
+    let labels: Vec<u8> = (0..n)
+        .map(|v| {
+            if assignment[label_variable(v, 2)] {
+                2
+            } else if assignment[label_variable(v, 1)] {
+                1
+            } else {
+                0
+            }
+        })
+        .collect();
+    let verification = verify(&graph.freeze(), &labels)?;
+    if let Some(&v) = verification.undominated.first() {
+        return Err(invalid(format!(
+            "{}: o modelo não é uma função de dominação romana: o vértice {} tem rótulo 0 e nenhum vizinho com rótulo 2",
+            file_path,
+            graph.get_vertex_name(v)
+        )));
+    }
+    Ok(Solution::from_labels(labels))
+}
@@ -0,0 +1,210 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::graph::Graph;
+
+// Regras de redução seguras para a dominação romana, aplicadas antes do AG. Cada regra
+// preserva o valor ótimo: γR(G) = γR(G') + custo da regra, e toda rotulação do grafo reduzido
+// pode ser levada de volta ao grafo original sem aumentar o peso além desse custo
+
+enum Reduction {
+    // Gêmeos falsos (mesma vizinhança aberta): basta manter alguns deles, pois numa solução
+    // ótima algum vértice da vizinhança recebe 2 e os gêmeos excedentes recebem 0
+    Twins {
+        neighbors: Vec<usize>,
+        kept: Vec<usize>,
+        removed: Vec<usize>,
+    },
+    // Caminho pendente x - u - v - w com w folha e u, v de grau 2: custa exatamente 2
+    // (v recebe 2, u e w recebem 0) e não afeta o restante do grafo
+    PendantPath {
+        u: usize,
+        v: usize,
+        w: usize,
+    },
+}
+
+pub struct Kernel {
+    graph: Graph,
+    original: Graph,
+    // vertex_map[i] é o vértice do grafo original que corresponde ao vértice i do reduzido
+    vertex_map: Vec<usize>,
+    steps: Vec<Reduction>,
+}
+
+impl Kernel {
+    pub fn get_graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    // Custo total das regras aplicadas: γR(G) = γR(G') + get_cost()
+    pub fn get_cost(&self) -> usize {
+        self.steps
+            .iter()
+            .map(|step| match step {
+                Reduction::Twins { .. } => 0,
+                Reduction::PendantPath { .. } => 2,
+            })
+            .sum()
+    }
+
+    // Leva uma rotulação do grafo reduzido a uma rotulação do grafo original
+    pub fn lift(&self, reduced_labels: &[u8]) -> Vec<u8> {
+        let mut labels = vec![0; self.original.get_num_vertices()];
+        for (i, &v) in self.vertex_map.iter().enumerate() {
+            labels[v] = reduced_labels[i];
+        }
+
+        // Desfaz as reduções na ordem inversa; a cada passo a rotulação é uma função de
+        // dominação romana do grafo daquele estágio
+        for step in self.steps.iter().rev() {
+            match step {
+                Reduction::Twins {
+                    neighbors,
+                    kept,
+                    removed,
+                } => {
+                    if !neighbors.iter().any(|&x| labels[x] == 2) {
+                        // Normaliza: um vizinho passa a 2 e domina todos os gêmeos, que recebem
+                        // 0. Um gêmeo com 2 só é mantido se algum outro vizinho com rótulo 0
+                        // dependia dele; vértices ainda não restaurados têm rótulo 0 e não
+                        // interferem na verificação
+                        labels[neighbors[0]] = 2;
+                        let twin_with_2 = kept.iter().find(|&&t| labels[t] == 2).copied();
+                        for &t in kept {
+                            labels[t] = 0;
+                        }
+                        let dominated = |x: usize| {
+                            self.original
+                                .get_neighbors(x)
+                                .iter()
+                                .any(|&y| labels[y] == 2)
+                        };
+                        if neighbors.iter().any(|&x| labels[x] == 0 && !dominated(x)) {
+                            if let Some(t) = twin_with_2 {
+                                labels[t] = 2;
+                            }
+                        }
+                    }
+                    for &t in removed {
+                        labels[t] = 0;
+                    }
+                }
+                Reduction::PendantPath { u, v, w } => {
+                    labels[*u] = 0;
+                    labels[*v] = 2;
+                    labels[*w] = 0;
+                }
+            }
+        }
+
+        let weight = |labels: &[u8]| labels.iter().map(|&l| l as usize).sum::<usize>();
+        debug_assert!(
+            weight(&labels) <= weight(reduced_labels) + self.get_cost(),
+            "a rotulação levada ao grafo original ultrapassou o custo das reduções"
+        );
+        labels
+    }
+}
+
+fn remove_vertex(adjacency: &mut [BTreeSet<usize>], alive: &mut [bool], v: usize) {
+    let neighbors = std::mem::take(&mut adjacency[v]);
+    for u in neighbors {
+        adjacency[u].remove(&v);
+    }
+    alive[v] = false;
+}
+
+// Mantém 2 folhas por vértice suporte e 3 gêmeos nos demais casos. O vértice suporte não é
+// fixado em 2 e retirado: seus vizinhos ficariam dominados de graça, o que o grafo reduzido,
+// uma instância comum do problema, não consegue expressar; as duas folhas mantidas cumprem
+// esse papel com o mesmo custo
+fn reduce_twins(
+    adjacency: &mut [BTreeSet<usize>],
+    alive: &mut [bool],
+    steps: &mut Vec<Reduction>,
+) -> bool {
+    let mut groups: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+    for v in 0..adjacency.len() {
+        if alive[v] && !adjacency[v].is_empty() {
+            let key: Vec<usize> = adjacency[v].iter().copied().collect();
+            groups.entry(key).or_default().push(v);
+        }
+    }
+    let mut groups: Vec<Vec<usize>> = groups.into_values().filter(|g| g.len() > 2).collect();
+    groups.sort_unstable();
+
+    let mut changed = false;
+    for group in groups {
+        // A vizinhança é relida porque grupos anteriores podem ter removido vértices dela
+        let neighbors: Vec<usize> = adjacency[group[0]].iter().copied().collect();
+        let keep = if neighbors.len() == 1 { 2 } else { 3 };
+        if neighbors.is_empty() || group.len() <= keep {
+            continue;
+        }
+
+        let (kept, removed) = group.split_at(keep);
+        for &t in removed {
+            remove_vertex(adjacency, alive, t);
+        }
+        steps.push(Reduction::Twins {
+            neighbors,
+            kept: kept.to_vec(),
+            removed: removed.to_vec(),
+        });
+        changed = true;
+    }
+    changed
+}
+
+fn reduce_pendant_paths(
+    adjacency: &mut [BTreeSet<usize>],
+    alive: &mut [bool],
+    steps: &mut Vec<Reduction>,
+) -> bool {
+    let mut changed = false;
+    for w in 0..adjacency.len() {
+        if !alive[w] || adjacency[w].len() != 1 {
+            continue;
+        }
+        let v = *adjacency[w].first().unwrap();
+        if adjacency[v].len() != 2 {
+            continue;
+        }
+        let u = *adjacency[v].iter().find(|&&x| x != w).unwrap();
+        if adjacency[u].len() != 2 {
+            continue;
+        }
+        for x in [u, v, w] {
+            remove_vertex(adjacency, alive, x);
+        }
+        steps.push(Reduction::PendantPath { u, v, w });
+        changed = true;
+    }
+    changed
+}
+
+// Aplica as regras até que nenhuma delas altere o grafo
+pub fn reduce(graph: &Graph) -> Kernel {
+    let n = graph.get_num_vertices();
+    let mut adjacency: Vec<BTreeSet<usize>> = (0..n)
+        .map(|v| graph.get_neighbors(v).iter().copied().collect())
+        .collect();
+    let mut alive = vec![true; n];
+    let mut steps = vec![];
+
+    loop {
+        let twins = reduce_twins(&mut adjacency, &mut alive, &mut steps);
+        let paths = reduce_pendant_paths(&mut adjacency, &mut alive, &mut steps);
+        if !twins && !paths {
+            break;
+        }
+    }
+
+    let vertex_map: Vec<usize> = (0..n).filter(|&v| alive[v]).collect();
+    Kernel {
+        graph: graph.induced_subgraph(&vertex_map),
+        original: graph.clone(),
+        vertex_map,
+        steps,
+    }
+}
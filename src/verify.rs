@@ -0,0 +1,220 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufWriter, Write},
+};
+
+use crate::graph::{CsrGraph, Graph};
+
+// Resultado da verificação de uma rotulação: a rotulação é uma função de dominação romana
+// quando nenhum vértice com rótulo 0 fica sem vizinho com rótulo 2
+#[derive(Clone, Debug)]
+pub struct Verification {
+    // Vértices com rótulo 0 e nenhum vizinho com rótulo 2
+    pub undominated: Vec<usize>,
+    pub weight: usize,
+    // label_counts[l] = |V_l|
+    pub label_counts: [usize; 3],
+}
+
+impl Verification {
+    pub fn is_valid(&self) -> bool {
+        self.undominated.is_empty()
+    }
+}
+
+// Vértices com rótulo 0 e nenhum vizinho com rótulo 2, gerados sob demanda
+fn undominated_vertices<'a>(
+    graph: &'a CsrGraph,
+    labels: &'a [u8],
+) -> impl Iterator<Item = usize> + 'a {
+    (0..graph.get_num_vertices()).filter(move |&v| {
+        labels[v] == 0
+            && !graph
+                .get_neighbors(v)
+                .iter()
+                .any(|&u| labels[u as usize] == 2)
+    })
+}
+
+// Teste rápido de viabilidade, sem alocação, que para no primeiro vértice não dominado. Usado
+// pelo AG a cada avaliação; rótulos fora de {0, 1, 2} contam como positivos
+pub fn is_roman_dominating(graph: &CsrGraph, labels: &[u8]) -> bool {
+    labels.len() == graph.get_num_vertices() && undominated_vertices(graph, labels).next().is_none()
+}
+
+// Verifica uma rotulação qualquer. Rotulações com tamanho diferente do número de vértices ou
+// com rótulos fora de {0, 1, 2} não descrevem uma função do grafo e geram erro. A dominação é
+// conferida pelo mesmo critério de is_roman_dominating, usado pelo AG
+pub fn verify(graph: &CsrGraph, labels: &[u8]) -> io::Result<Verification> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+    if labels.len() != graph.get_num_vertices() {
+        return Err(invalid(format!(
+            "a rotulação tem {} rótulos, mas o grafo tem {} vértices",
+            labels.len(),
+            graph.get_num_vertices()
+        )));
+    }
+
+    let mut label_counts = [0; 3];
+    for (v, &label) in labels.iter().enumerate() {
+        let count = label_counts.get_mut(label as usize).ok_or_else(|| {
+            invalid(format!(
+                "o vértice {} tem rótulo inválido {} (esperado 0, 1 ou 2)",
+                v, label
+            ))
+        })?;
+        *count += 1;
+    }
+    let undominated = undominated_vertices(graph, labels).collect();
+
+    Ok(Verification {
+        undominated,
+        weight: label_counts[1] + 2 * label_counts[2],
+        label_counts,
+    })
+}
+
+// Lê um arquivo de rotulação. Cada linha traz "vértice rótulo", com o vértice pelo índice
+// (a partir de 0) ou pelo nome original quando o grafo tem nomes, ou apenas "rótulo", que vale
+// para o próximo vértice em ordem. Linhas vazias e comentários ("#" ou "%") são ignorados.
+// Todo vértice deve receber exatamente um rótulo 0, 1 ou 2
+pub fn read_labels(graph: &Graph, file_path: String) -> io::Result<Vec<u8>> {
+    let file = File::open(&file_path)?;
+    let reader = io::BufReader::new(file);
+    let n = graph.get_num_vertices();
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+    let mut labels: Vec<Option<u8>> = vec![None; n];
+    let mut next_vertex = 0;
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line_number = line_number + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('%') {
+            continue;
+        }
+
+        let tokens: Vec<&str> = trimmed.split_whitespace().collect();
+        let (vertex, label) = match tokens[..] {
+            [label] => (Some(next_vertex), label),
+            [vertex, label] => {
+                let index = match graph.get_vertex_names() {
+                    Some(names) => names.get_index(vertex),
+                    None => vertex.parse::<usize>().ok(),
+                };
+                (index, label)
+            }
+            _ => {
+                return Err(invalid(format!(
+                    "{}:{}: esperado 'vértice rótulo' ou 'rótulo': '{}'",
+                    file_path, line_number, line
+                )))
+            }
+        };
+
+        let vertex = vertex.filter(|&v| v < n).ok_or_else(|| {
+            invalid(format!(
+                "{}:{}: vértice inválido em '{}'",
+                file_path, line_number, line
+            ))
+        })?;
+        let label = label
+            .parse::<u8>()
+            .ok()
+            .filter(|&l| l <= 2)
+            .ok_or_else(|| {
+                invalid(format!(
+                    "{}:{}: rótulo inválido '{}' (esperado 0, 1 ou 2)",
+                    file_path, line_number, label
+                ))
+            })?;
+        if labels[vertex].replace(label).is_some() {
+            return Err(invalid(format!(
+                "{}:{}: o vértice {} foi rotulado mais de uma vez",
+                file_path,
+                line_number,
+                graph.get_vertex_name(vertex)
+            )));
+        }
+        next_vertex = vertex + 1;
+    }
+
+    labels
+        .iter()
+        .enumerate()
+        .map(|(v, label)| {
+            label.ok_or_else(|| {
+                invalid(format!(
+                    "{}: o vértice {} não foi rotulado",
+                    file_path,
+                    graph.get_vertex_name(v)
+                ))
+            })
+        })
+        .collect()
+}
+
+// Escreve a rotulação no formato lido por read_labels: uma linha "vértice rótulo" por vértice,
+// com o nome original quando o grafo tem nomes, precedidas de um comentário com o peso
+pub fn write_labels(graph: &Graph, labels: &[u8], file_path: String) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(&file_path)?);
+    let weight: usize = labels.iter().map(|&l| l as usize).sum();
+
+    writeln!(
+        writer,
+        "# {} vértices, peso {}",
+        graph.get_num_vertices(),
+        weight
+    )?;
+    for (v, label) in labels.iter().enumerate() {
+        writeln!(writer, "{} {}", graph.get_vertex_name(v), label)?;
+    }
+    writer.flush()
+}
+
+// Rotulações de várias execuções, uma por linha: "execução,peso,rótulos", com os rótulos dos
+// vértices 0..n concatenados (por exemplo "1,8,0200100..."). Quando o grafo tem nomes
+// originais (--compact-ids), os índices compactados não identificam os vértices da entrada e
+// cada execução ocupa uma linha "execução,peso,vértice,rótulo" por vértice, com o nome
+pub struct TrialLabelsWriter {
+    writer: BufWriter<File>,
+    names: Option<Vec<String>>,
+}
+
+impl TrialLabelsWriter {
+    pub fn create(graph: &Graph, file_path: String) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(&file_path)?);
+        let names: Option<Vec<String>> = graph.get_vertex_names().map(|_| {
+            (0..graph.get_num_vertices())
+                .map(|v| graph.get_vertex_name(v))
+                .collect()
+        });
+        if names.is_some() {
+            writeln!(writer, "trial,fitness_value,vertex,label")?;
+        } else {
+            writeln!(writer, "trial,fitness_value,labels")?;
+        }
+        Ok(TrialLabelsWriter { writer, names })
+    }
+
+    pub fn write(&mut self, trial: usize, labels: &[u8]) -> io::Result<()> {
+        let weight: usize = labels.iter().map(|&l| l as usize).sum();
+        if let Some(names) = &self.names {
+            for (name, label) in names.iter().zip(labels) {
+                writeln!(self.writer, "{},{},{},{}", trial, weight, name, label)?;
+            }
+            return Ok(());
+        }
+        let labels: String = labels.iter().map(|&l| (b'0' + l) as char).collect();
+        writeln!(self.writer, "{},{},{}", trial, weight, labels)
+    }
+
+    pub fn finish(mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+pub fn verify_file(graph: &Graph, file_path: String) -> io::Result<Verification> {
+    let labels = read_labels(graph, file_path)?;
+    verify(&graph.freeze(), &labels)
+}